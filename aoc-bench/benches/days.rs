@@ -0,0 +1,70 @@
+//! Benchmarks parsing and solving each day against its checked-in input, grouped by day.
+//!
+//! Run a single day with `cargo bench -p aoc-bench -- day-15`. Criterion compares every run
+//! against the previous one; to compare an optimisation against a fixed point instead, record
+//! a baseline with `-- --save-baseline before` and then run with `-- --baseline before`.
+#[macro_use]
+extern crate criterion;
+
+use aoc_common::{Params, Solution};
+use criterion::{Benchmark, Criterion};
+
+/// Some solutions take seconds per iteration, so keep the number of samples down.
+const SAMPLE_SIZE: usize = 10;
+
+fn params(values: &[(&str, &str)]) -> Params {
+    let mut params = Params::new();
+    for (name, value) in values {
+        params.insert(name, value);
+    }
+    params
+}
+
+/// Benches a day's parse, part 1 and part 2 separately, as the group `day-N`.
+fn bench_day<S>(c: &mut Criterion, input: &'static str, param_values: &'static [(&str, &str)])
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    let parse = |input| S::parse(input, &params(param_values)).expect("Unable to parse input");
+    let part1_input = parse(input);
+    let part2_input = parse(input);
+    c.bench(
+        &format!("day-{}", S::DAY),
+        Benchmark::new("parse", move |b| b.iter(|| S::parse(input, &params(param_values))))
+            .with_function("part 1", move |b| b.iter(|| S::part1(&part1_input)))
+            .with_function("part 2", move |b| b.iter(|| S::part2(&part2_input)))
+            .sample_size(SAMPLE_SIZE),
+    );
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day_1::Day1>(c, include_str!("../../day-1/input/input1.txt"), &[]);
+    bench_day::<day_2::Day2>(c, include_str!("../../day-2/input/input2.txt"), &[]);
+    bench_day::<day_3::Day3>(c, include_str!("../../day-3/input/input1.txt"), &[]);
+    bench_day::<day_4::Day4>(c, include_str!("../../day-4/input/input2.txt"), &[]);
+    bench_day::<day_5::Day5>(c, include_str!("../../day-5/input/input2.txt"), &[]);
+    bench_day::<day_6::Day6>(c, include_str!("../../day-6/input/input2.txt"), &[]);
+    bench_day::<day_7::Day7>(c, include_str!("../../day-7/input/input2.txt"), &[]);
+    bench_day::<day_8::Day8>(c, include_str!("../../day-8/input/input2.txt"), &[]);
+    bench_day::<day_9::Day9>(c, "", &[("players", "30"), ("last_marble", "5807")]);
+    bench_day::<day_10::Day10>(c, include_str!("../../day-10/input/input2.txt"), &[]);
+    bench_day::<day_11::Day11>(c, "", &[("serial", "18")]);
+    bench_day::<day_12::Day12>(c, include_str!("../../day-12/input/input2.txt"), &[]);
+    bench_day::<day_13::Day13>(c, include_str!("../../day-13/input/input2.txt"), &[]);
+    bench_day::<day_14::Day14>(c, "", &[("recipes", "59414")]);
+    bench_day::<day_15::Day15>(c, include_str!("../../day-15/input/input8.txt"), &[]);
+    bench_day::<day_16::Day16>(c, include_str!("../../day-16/input/input2.txt"), &[]);
+    bench_day::<day_17::Day17>(c, include_str!("../../day-17/input/input2.txt"), &[]);
+    bench_day::<day_18::Day18>(c, include_str!("../../day-18/input/input2.txt"), &[]);
+    bench_day::<day_19::Day19>(c, include_str!("../../day-19/input/input2.txt"), &[]);
+    bench_day::<day_20::Day20>(c, include_str!("../../day-20/input/input6.txt"), &[]);
+    bench_day::<day_21::Day21>(c, include_str!("../../day-21/input/input1.txt"), &[]);
+    bench_day::<day_22::Day22>(c, "", &[("depth", "510"), ("target_x", "10"), ("target_y", "10")]);
+    bench_day::<day_23::Day23>(c, include_str!("../../day-23/input/input2.txt"), &[]);
+    bench_day::<day_24::Day24>(c, include_str!("../../day-24/input/input2.txt"), &[]);
+    bench_day::<day_25::Day25>(c, include_str!("../../day-25/input/input5.txt"), &[]);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
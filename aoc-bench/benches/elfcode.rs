@@ -0,0 +1,50 @@
+//! Measures how many ElfCode instructions the machine runs per second, on the day 19 and
+//! day 21 programs. Criterion reports the rate as elements per second.
+#[macro_use]
+extern crate criterion;
+
+use std::convert::TryFrom;
+
+use criterion::{Benchmark, Criterion, Throughput};
+use elfcode::{Compiled, Machine, Program};
+
+const SAMPLE_SIZE: usize = 10;
+
+/// Benches running the program from a fresh machine until `stop`, or until it halts.
+fn bench_program<F>(c: &mut Criterion, name: &str, input: &str, stop: F)
+where
+    F: Fn(usize, &[usize]) -> bool + Copy + 'static,
+{
+    let program: Compiled = Program::parse(input, 6).expect("Unable to parse program").compile();
+    let mut machine = Machine::for_program(&program);
+    machine.run_until(&program, stop);
+    // Criterion only counts elements in a u32, and a truncated count would report the wrong rate
+    let executed = u32::try_from(machine.executed).unwrap_or_else(|_| {
+        panic!(
+            "The {} program ran {} instructions, more than a throughput can count",
+            name, machine.executed
+        )
+    });
+    c.bench(
+        "elfcode",
+        Benchmark::new(name, move |b| {
+            b.iter(|| {
+                let mut machine = Machine::for_program(&program);
+                machine.run_until(&program, stop);
+                machine.registers()[0]
+            })
+        })
+        .throughput(Throughput::Elements(executed))
+        .sample_size(SAMPLE_SIZE),
+    );
+}
+
+fn programs(c: &mut Criterion) {
+    bench_program(c, "day-19", include_str!("../../day-19/input/input2.txt"), |_, _| false);
+    // Register 0 is never set, so the day 21 program would run forever without stopping at the
+    // comparison that decides whether to halt
+    bench_program(c, "day-21", include_str!("../../day-21/input/input1.txt"), |ip, _| ip == 28);
+}
+
+criterion_group!(benches, programs);
+criterion_main!(benches);
@@ -0,0 +1,282 @@
+use std::mem;
+
+use crate::grid::{Grid, Point};
+
+/// Which cells around a cell its rule looks at, as offsets from it, in the order the rule sees
+/// them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Neighbourhood {
+    offsets: Vec<Point>,
+}
+
+impl Neighbourhood {
+    pub fn new(offsets: Vec<Point>) -> Neighbourhood {
+        Neighbourhood { offsets }
+    }
+
+    /// Every cell within `radius` along a line, including the cell itself, from left to right.
+    pub fn window(radius: i32) -> Neighbourhood {
+        Neighbourhood::new((-radius..=radius).map(|x| Point::new(x, 0)).collect())
+    }
+
+    /// Every other cell within `radius` across and down, including diagonally, in reading
+    /// order.
+    pub fn moore(radius: i32) -> Neighbourhood {
+        Neighbourhood::within(radius, |_offset| true)
+    }
+
+    /// Every other cell within `radius` steps across and down, in reading order.
+    pub fn von_neumann(radius: i32) -> Neighbourhood {
+        Neighbourhood::within(radius, |offset| offset.manhattan_distance(Point::new(0, 0)) <= radius)
+    }
+
+    fn within<F: Fn(Point) -> bool>(radius: i32, include: F) -> Neighbourhood {
+        Neighbourhood::new(
+            (-radius..=radius)
+                .flat_map(|y| (-radius..=radius).map(move |x| Point::new(x, y)))
+                .filter(|&offset| offset != Point::new(0, 0) && include(offset))
+                .collect(),
+        )
+    }
+
+    pub fn offsets(&self) -> &[Point] {
+        &self.offsets
+    }
+
+    /// How far the neighbourhood reaches across and down.
+    fn reach(&self) -> (i32, i32) {
+        self.offsets
+            .iter()
+            .fold((0, 0), |(x, y), offset| (x.max(offset.x.abs()), y.max(offset.y.abs())))
+    }
+}
+
+/// What happens at the edges of the grid.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Edges {
+    /// The grid stays the same size, and cells beyond it count as blank.
+    Fixed,
+    /// The grid stays the same size, and wraps round, so that going off one edge comes back
+    /// on at the opposite edge.
+    Wrapping,
+    /// The grid grows to hold every cell that isn't blank, and shrinks to fit them, so the
+    /// cells can spread as far as they like. The rule must leave a blank cell with only blank
+    /// neighbours blank.
+    Growing,
+}
+
+/// A cellular automaton, where each generation every cell changes according to `rule`, given
+/// the cell and its neighbourhood.
+#[derive(Clone, Debug)]
+pub struct Automaton<T, R> {
+    neighbourhood: Neighbourhood,
+    edges: Edges,
+    blank: T,
+    rule: R,
+}
+
+impl<T, R> Automaton<T, R>
+where
+    T: Clone + PartialEq,
+    R: Fn(&T, &[&T]) -> T,
+{
+    pub fn new(neighbourhood: Neighbourhood, edges: Edges, blank: T, rule: R) -> Automaton<T, R> {
+        Automaton {
+            neighbourhood,
+            edges,
+            blank,
+            rule,
+        }
+    }
+
+    /// Works out the generation after `cells` into `next`, reusing its storage where it can.
+    pub fn step_into(&self, cells: &Grid<T>, next: &mut Grid<T>) {
+        let mut neighbours = Vec::with_capacity(self.neighbourhood.offsets.len());
+        match self.edges {
+            Edges::Fixed | Edges::Wrapping => {
+                if (next.origin(), next.width(), next.height()) != (cells.origin(), cells.width(), cells.height()) {
+                    *next = cells.clone();
+                }
+                for point in cells.points() {
+                    next[point] = self.apply(cells, point, &mut neighbours);
+                }
+            }
+            Edges::Growing => {
+                let (reach_x, reach_y) = self.neighbourhood.reach();
+                let grown = Grid::from_fn(
+                    cells.origin().offset(-reach_x, -reach_y),
+                    cells.width() + 2 * reach_x as usize,
+                    cells.height() + 2 * reach_y as usize,
+                    |point| self.apply(cells, point, &mut neighbours),
+                );
+                *next = self.trim(&grown);
+            }
+        }
+    }
+
+    /// The generation after `cells`.
+    pub fn step(&self, cells: &Grid<T>) -> Grid<T> {
+        let mut next = cells.clone();
+        self.step_into(cells, &mut next);
+        next
+    }
+
+    /// Every generation from `cells` on, starting with `cells` themselves.
+    pub fn run(&self, cells: Grid<T>) -> Generations<'_, T, R> {
+        Generations {
+            automaton: self,
+            buffer: cells.clone(),
+            cells,
+            started: false,
+        }
+    }
+
+    fn apply<'a>(&'a self, cells: &'a Grid<T>, point: Point, neighbours: &mut Vec<&'a T>) -> T {
+        neighbours.clear();
+        neighbours.extend(
+            self.neighbourhood
+                .offsets
+                .iter()
+                .map(|offset| {
+                    let neighbour = point.offset(offset.x, offset.y);
+                    let neighbour = match self.edges {
+                        Edges::Wrapping => wrap(cells, neighbour),
+                        Edges::Fixed | Edges::Growing => neighbour,
+                    };
+                    cells.get(neighbour).unwrap_or(&self.blank)
+                }),
+        );
+        (self.rule)(cells.get(point).unwrap_or(&self.blank), neighbours)
+    }
+
+    /// Cuts the grid down to the smallest rectangle holding every cell that isn't blank.
+    fn trim(&self, cells: &Grid<T>) -> Grid<T> {
+        let bounds = cells
+            .iter()
+            .filter(|&(_point, cell)| *cell != self.blank)
+            .fold(None, |bounds: Option<(Point, Point)>, (point, _cell)| match bounds {
+                Some((min, max)) => Some((
+                    Point::new(min.x.min(point.x), min.y.min(point.y)),
+                    Point::new(max.x.max(point.x), max.y.max(point.y)),
+                )),
+                None => Some((point, point)),
+            });
+        match bounds {
+            Some((min, max)) => Grid::from_fn(
+                min,
+                (max.x - min.x + 1) as usize,
+                (max.y - min.y + 1) as usize,
+                |point| cells[point].clone(),
+            ),
+            None => Grid::new(cells.origin(), 0, 0, self.blank.clone()),
+        }
+    }
+}
+
+/// The point in the grid that's the same distance from its edges as the given point, if the
+/// grid were repeated in every direction. An empty grid has nowhere to wrap to, so the point
+/// is left as it is.
+fn wrap<T>(cells: &Grid<T>, point: Point) -> Point {
+    if cells.width() == 0 || cells.height() == 0 {
+        return point;
+    }
+    let origin = cells.origin();
+    Point::new(
+        origin.x + (point.x - origin.x).rem_euclid(cells.width() as i32),
+        origin.y + (point.y - origin.y).rem_euclid(cells.height() as i32),
+    )
+}
+
+/// The generations of an automaton, worked out one after another in a pair of buffers.
+pub struct Generations<'a, T, R> {
+    automaton: &'a Automaton<T, R>,
+    cells: Grid<T>,
+    buffer: Grid<T>,
+    started: bool,
+}
+
+impl<'a, T, R> Generations<'a, T, R>
+where
+    T: Clone + PartialEq,
+    R: Fn(&T, &[&T]) -> T,
+{
+    /// The latest generation.
+    pub fn cells(&self) -> &Grid<T> {
+        &self.cells
+    }
+
+    /// Moves on to the next generation without copying it out.
+    pub fn advance(&mut self) {
+        self.automaton.step_into(&self.cells, &mut self.buffer);
+        mem::swap(&mut self.cells, &mut self.buffer);
+    }
+}
+
+impl<'a, T, R> Iterator for Generations<'a, T, R>
+where
+    T: Clone + PartialEq,
+    R: Fn(&T, &[&T]) -> T,
+{
+    type Item = Grid<T>;
+
+    fn next(&mut self) -> Option<Grid<T>> {
+        if self.started {
+            self.advance();
+        }
+        self.started = true;
+        Some(self.cells.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn life(cell: &bool, neighbours: &[&bool]) -> bool {
+        let alive = neighbours.iter().filter(|&&&neighbour| neighbour).count();
+        alive == 3 || (*cell && alive == 2)
+    }
+
+    fn read(cells: &str) -> Grid<bool> {
+        Grid::parse(cells, false, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .unwrap()
+    }
+
+    fn render(cells: &Grid<bool>) -> String {
+        cells.render(|&cell| if cell { '#' } else { '.' })
+    }
+
+    #[test]
+    fn a_glider_moves_on_a_growing_grid() {
+        let automaton = Automaton::new(Neighbourhood::moore(1), Edges::Growing, false, life);
+        let glider = read(".#.\n..#\n###");
+        let generations: Vec<Grid<bool>> = automaton.run(glider.clone()).take(5).collect();
+        assert_eq!(render(&generations[1]), "#.#\n.##\n.#.\n");
+        assert_eq!(generations[4].origin(), Point::new(1, 1));
+        assert_eq!(render(&generations[4]), render(&glider));
+    }
+
+    #[test]
+    fn a_fixed_grid_keeps_its_size() {
+        let automaton = Automaton::new(Neighbourhood::moore(1), Edges::Fixed, false, life);
+        let mut generations = automaton.run(read(".....\n.###.\n....."));
+        generations.advance();
+        assert_eq!(render(generations.cells()), "..#..\n..#..\n..#..\n");
+        generations.advance();
+        assert_eq!(render(generations.cells()), ".....\n.###.\n.....\n");
+
+        // With the edges wrapped round, a blinker along the top row spills onto the bottom row
+        let automaton = Automaton::new(Neighbourhood::moore(1), Edges::Wrapping, false, life);
+        let mut generations = automaton.run(read(".###.\n.....\n.....\n....."));
+        generations.advance();
+        assert_eq!(render(generations.cells()), "..#..\n..#..\n.....\n..#..\n");
+        assert_eq!(automaton.step(&read("")), read(""));
+        assert_eq!(wrap(&read(""), Point::new(3, -1)), Point::new(3, -1));
+        assert_eq!(Neighbourhood::von_neumann(1).offsets().len(), 4);
+        assert_eq!(Neighbourhood::window(2).offsets()[0], Point::new(-2, 0));
+    }
+}
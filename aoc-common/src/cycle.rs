@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a sequence of states goes round in a loop: the state at step `start + period` is the
+/// same as at step `start`, and the same `period` states repeat from then on. The first state
+/// is step 0.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as `step`, which is at most `start + period - 1`.
+    pub fn first_equivalent(&self, step: u64) -> usize {
+        let start = self.start as u64;
+        if step < start {
+            step as usize
+        } else {
+            (start + (step - start) % self.period as u64) as usize
+        }
+    }
+
+    /// Extrapolates a value worked out from each state to `step`, given its value at every step
+    /// up to and including the first repeat. The value can drift by the same amount each time
+    /// round the loop, as with the total position of a pattern that moves as it repeats.
+    ///
+    /// Panics if there are fewer than `start + period + 1` values.
+    pub fn extrapolate(&self, values: &[i64], step: u64) -> i64 {
+        let first = self.first_equivalent(step);
+        let laps = (step - first as u64) / self.period as u64;
+        let drift = values[self.start + self.period] - values[self.start];
+        values[first] + laps as i64 * drift
+    }
+}
+
+/// Finds the first state that repeats an earlier one, remembering every state along the way.
+/// Returns the cycle, with the states up to and including the repeat, or None if the states
+/// run out first.
+pub fn find_cycle<S, I>(states: I) -> Option<(Cycle, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    find_cycle_by_key(states, S::clone)
+}
+
+/// Like `find_cycle`, but treats states as the same if they have the same key, so that the
+/// states themselves can carry values that change between repeats.
+pub fn find_cycle_by_key<S, K, I, F>(states: I, mut key: F) -> Option<(Cycle, Vec<S>)>
+where
+    K: Eq + Hash,
+    I: IntoIterator<Item = S>,
+    F: FnMut(&S) -> K,
+{
+    let mut seen = HashMap::new();
+    let mut history = Vec::new();
+    for (step, state) in states.into_iter().enumerate() {
+        let state_key = key(&state);
+        history.push(state);
+        if let Some(&start) = seen.get(&state_key) {
+            return Some((
+                Cycle {
+                    start,
+                    period: step - start,
+                },
+                history,
+            ));
+        }
+        seen.insert(state_key, step);
+    }
+    None
+}
+
+/// Finds the cycle that repeatedly applying `step` from `initial` goes round, with Brent's
+/// algorithm, which only keeps two states at a time. Never returns if the states never repeat.
+pub fn brent<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    // Find the period by moving the tortoise up to the hare at every power of two steps, until
+    // the hare comes back round to it
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // Find the start by moving both along with the hare a period ahead, until they meet
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, period }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, then round 5, 6, 7, 8 forever
+    fn next(&state: &u32) -> u32 {
+        if state < 8 {
+            state + 1
+        } else {
+            5
+        }
+    }
+
+    #[test]
+    fn finds_the_same_cycle_both_ways() {
+        let expected = Cycle { start: 5, period: 4 };
+        assert_eq!(brent(0, next), expected);
+        let (cycle, history) = find_cycle(std::iter::successors(Some(0), |state| Some(next(state)))).unwrap();
+        assert_eq!(cycle, expected);
+        assert_eq!(history, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 5]);
+        assert_eq!(find_cycle(0..10), None);
+        assert_eq!(brent(3, |_| 3), Cycle { start: 0, period: 1 });
+    }
+
+    #[test]
+    fn extrapolates_a_drifting_value() {
+        // A state that goes round 5, 6, 7, 8 while moving 10 further along each time round
+        let states = std::iter::successors(Some((0, 0)), |&(state, position)| {
+            Some((next(&state), if state == 8 { position + 10 } else { position }))
+        });
+        let (cycle, history) = find_cycle_by_key(states, |&(state, _position)| state).unwrap();
+        assert_eq!(cycle, Cycle { start: 5, period: 4 });
+        let positions: Vec<i64> = history.iter().map(|&(state, position)| i64::from(state) + position).collect();
+        assert_eq!(cycle.first_equivalent(3), 3);
+        assert_eq!(cycle.first_equivalent(1_000_000_001), 5);
+        assert_eq!(cycle.extrapolate(&positions, 3), 3);
+        assert_eq!(cycle.extrapolate(&positions, 14), 6 + 20);
+        assert_eq!(cycle.extrapolate(&positions, 1_000_000_001), 5 + 2_499_999_990);
+    }
+}
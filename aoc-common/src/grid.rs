@@ -0,0 +1,310 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::input::{lines, InputError};
+
+/// A position on a grid, with y increasing downwards. Points are ordered in reading order,
+/// top to bottom and then left to right.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// The offsets of the points sharing an edge with a point, in reading order.
+const NEIGHBOURS_4: [(i32, i32); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// The offsets of the points around a point, including diagonally, in reading order.
+const NEIGHBOURS_8: [(i32, i32); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+impl Point {
+    pub const fn new(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+
+    pub fn offset(self, dx: i32, dy: i32) -> Point {
+        Point::new(self.x + dx, self.y + dy)
+    }
+
+    pub fn up(self) -> Point {
+        self.offset(0, -1)
+    }
+
+    pub fn down(self) -> Point {
+        self.offset(0, 1)
+    }
+
+    pub fn left(self) -> Point {
+        self.offset(-1, 0)
+    }
+
+    pub fn right(self) -> Point {
+        self.offset(1, 0)
+    }
+
+    /// The four points sharing an edge with this one, in reading order.
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        NEIGHBOURS_4.iter().map(move |&(dx, dy)| self.offset(dx, dy))
+    }
+
+    /// The eight points around this one, including diagonally, in reading order.
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        NEIGHBOURS_8.iter().map(move |&(dx, dy)| self.offset(dx, dy))
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+impl Ord for Point {
+    fn cmp(&self, other: &Point) -> Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Point) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A dense rectangle of cells, `width` by `height`, with its top left at `origin`. The origin
+/// can be anywhere, so a grid can cover just the part of a world that matters.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    origin: Point,
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(origin: Point, width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            origin,
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// A grid with each cell worked out from its point.
+    pub fn from_fn<F: FnMut(Point) -> T>(origin: Point, width: usize, height: usize, mut cell: F) -> Grid<T> {
+        let cells = points(origin, width, height).map(&mut cell).collect();
+        Grid {
+            origin,
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Reads a map with one character per cell and its top left at (0, 0). `cell` turns each
+    /// character into a cell, or returns None if the character isn't allowed. Lines shorter
+    /// than the longest are padded out with `blank`.
+    pub fn parse<F>(input: &str, blank: T, mut cell: F) -> Result<Grid<T>, InputError>
+    where
+        T: Clone,
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut rows = Vec::new();
+        for line in lines(input) {
+            let row = line
+                .text
+                .char_indices()
+                .map(|(offset, c)| {
+                    cell(c).ok_or_else(|| {
+                        line.error_at(
+                            &line.text[offset..offset + c.len_utf8()],
+                            format!("Unexpected character {:?}", c),
+                        )
+                    })
+                })
+                .collect::<Result<Vec<T>, InputError>>()?;
+            rows.push(row);
+        }
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for mut row in rows {
+            row.resize(width, blank.clone());
+            cells.append(&mut row);
+        }
+        Ok(Grid {
+            origin: Point::new(0, 0),
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// The top left point of the grid.
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        let x = point.x - self.origin.x;
+        let y = point.y - self.origin.y;
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return None;
+        }
+        Some(y as usize * self.width + x as usize)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        match self.index_of(point) {
+            Some(index) => Some(&mut self.cells[index]),
+            None => None,
+        }
+    }
+
+    /// Every point in the grid, in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        points(self.origin, self.width, self.height)
+    }
+
+    /// Every point in the grid with its cell, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// Every cell, in reading order.
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// The points sharing an edge with the point that are in the grid, with their cells, in
+    /// reading order.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbours4()
+            .filter_map(move |neighbour| self.get(neighbour).map(|cell| (neighbour, cell)))
+    }
+
+    /// The points around the point, including diagonally, that are in the grid, with their
+    /// cells, in reading order.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbours8()
+            .filter_map(move |neighbour| self.get(neighbour).map(|cell| (neighbour, cell)))
+    }
+
+    /// A grid of the same size and position, with each cell worked out from this one's.
+    pub fn map<U, F: FnMut(Point, &T) -> U>(&self, mut f: F) -> Grid<U> {
+        Grid {
+            origin: self.origin,
+            width: self.width,
+            height: self.height,
+            cells: self.iter().map(|(point, cell)| f(point, cell)).collect(),
+        }
+    }
+
+    /// Draws the grid one row per line, with a character for each cell.
+    pub fn render<F: Fn(&T) -> char>(&self, cell: F) -> String {
+        let mut rendered = String::with_capacity((self.width + 1) * self.height);
+        for row in self.cells.chunks(self.width.max(1)) {
+            rendered.extend(row.iter().map(&cell));
+            rendered.push('\n');
+        }
+        rendered
+    }
+}
+
+fn points(origin: Point, width: usize, height: usize) -> impl Iterator<Item = Point> {
+    (0..height as i32).flat_map(move |y| (0..width as i32).map(move |x| origin.offset(x, y)))
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    /// Panics if the point is outside the grid.
+    fn index(&self, point: Point) -> &T {
+        match self.index_of(point) {
+            Some(index) => &self.cells[index],
+            None => panic!("{:?} is outside the grid", point),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        match self.index_of(point) {
+            Some(index) => &mut self.cells[index],
+            None => panic!("{:?} is outside the grid", point),
+        }
+    }
+}
+
+/// Draws the grid one row per line, formatting each cell in turn.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_draws_a_map() {
+        let grid = Grid::parse("#.#\n.\n", ' ', |c| if c == '#' || c == '.' { Some(c) } else { None }).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 0)], '#');
+        assert_eq!(grid.to_string(), "#.#\n.  \n");
+
+        let error = Grid::parse("..\n.x", ' ', |c| if c == '.' { Some(c) } else { None }).unwrap_err();
+        assert_eq!(error.line_number(), 2);
+        assert_eq!(error.message(), "Unexpected character 'x'");
+    }
+
+    #[test]
+    fn indexes_from_its_origin() {
+        let grid = Grid::from_fn(Point::new(-1, 10), 3, 2, |point| point.x * 100 + point.y);
+        assert_eq!(grid[Point::new(-1, 10)], -90);
+        assert_eq!(grid.get(Point::new(1, 11)), Some(&111));
+        assert_eq!(grid.get(Point::new(2, 11)), None);
+        assert_eq!(grid.get(Point::new(0, 9)), None);
+        assert_eq!(grid.points().last(), Some(Point::new(1, 11)));
+    }
+
+    #[test]
+    fn finds_neighbours_in_reading_order() {
+        let grid = Grid::from_fn(Point::new(0, 0), 3, 3, |point| point);
+        let edges: Vec<Point> = grid.neighbours4(Point::new(0, 1)).map(|(point, _)| point).collect();
+        assert_eq!(edges, vec![Point::new(0, 0), Point::new(1, 1), Point::new(0, 2)]);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8(Point::new(2, 2)).count(), 3);
+        let mut sorted: Vec<Point> = Point::new(5, 5).neighbours8().collect();
+        sorted.sort();
+        assert_eq!(sorted, Point::new(5, 5).neighbours8().collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,300 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, Read};
+use std::ops::Range;
+use std::str::FromStr;
+
+use regex::{Captures, Regex};
+
+/// An error in a puzzle input, pointing at the part of the line that couldn't be read.
+#[derive(Debug)]
+pub struct InputError {
+    file: Option<String>,
+    line_number: usize,
+    line: String,
+    columns: Range<usize>,
+    message: String,
+}
+
+impl InputError {
+    /// Records the file that the input came from, for the error message, unless the error
+    /// already came from another file that the input named.
+    pub fn in_file(mut self, file: &str) -> InputError {
+        if self.file.is_none() {
+            self.file = Some(file.to_owned());
+        }
+        self
+    }
+
+    /// An error for when the input ran out after `line_count` lines, before what was `expected`.
+    pub fn end_of_input(line_count: usize, expected: &str) -> InputError {
+        Line::new(line_count, "").error(format!("Expected {}, found the end of the input", expected))
+    }
+
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The one-based column that the offending text starts at.
+    fn column(&self) -> usize {
+        self.line[..self.columns.start].chars().count() + 1
+    }
+}
+
+/// Renders the error followed by the line, with carets under the offending text.
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}: ", file, self.line_number, self.column())?,
+            None => write!(f, "line {}, column {}: ", self.line_number, self.column())?,
+        }
+        writeln!(f, "{}", self.message)?;
+        let gutter = " ".repeat(self.line_number.to_string().len());
+        let carets = self.line[self.columns.clone()].chars().count().max(1);
+        writeln!(f, "{} | {}", self.line_number, self.line)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(self.column() - 1),
+            "^".repeat(carets)
+        )
+    }
+}
+
+impl Error for InputError {}
+
+/// A line of a puzzle input, which knows where it came from so that errors can point into it.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    /// The zero-based index of the line in the input.
+    pub index: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(index: usize, text: &'a str) -> Line<'a> {
+        Line { index, text }
+    }
+
+    /// An error about the whole line.
+    pub fn error<S: Into<String>>(&self, message: S) -> InputError {
+        self.error_at_columns(0..self.text.len(), message)
+    }
+
+    /// An error about `field`, which must be a slice of this line's text, such as a regex capture
+    /// or one of the pieces of `split`. Anything else is treated as the whole line.
+    pub fn error_at<S: Into<String>>(&self, field: &str, message: S) -> InputError {
+        self.error_at_columns(self.columns_of(field), message)
+    }
+
+    fn error_at_columns<S: Into<String>>(&self, columns: Range<usize>, message: S) -> InputError {
+        InputError {
+            file: None,
+            line_number: self.index + 1,
+            line: self.text.to_owned(),
+            columns,
+            message: message.into(),
+        }
+    }
+
+    fn columns_of(&self, field: &str) -> Range<usize> {
+        let line_start = self.text.as_ptr() as usize;
+        let field_start = field.as_ptr() as usize;
+        if field_start < line_start || field_start + field.len() > line_start + self.text.len() {
+            return 0..self.text.len();
+        }
+        let start = field_start - line_start;
+        start..start + field.len()
+    }
+
+    /// Parses `field`, which must be a slice of this line's text.
+    pub fn parse<T>(&self, field: &str) -> Result<T, InputError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        field
+            .parse::<T>()
+            .map_err(|err| self.error_at(field, format!("Unable to parse {:?}: {}", field, err)))
+    }
+
+    /// Matches the line against the regex, describing what was `expected` if it doesn't match.
+    pub fn captures(&self, regex: &Regex, expected: &str) -> Result<Captures<'a>, InputError> {
+        regex
+            .captures(self.text)
+            .ok_or_else(|| self.error(format!("Expected {}", expected)))
+    }
+
+    /// Checks that every character in the line is one of the `allowed` characters.
+    pub fn check_chars(&self, allowed: &str) -> Result<(), InputError> {
+        match self.text.char_indices().find(|&(_, c)| !allowed.contains(c)) {
+            Some((offset, c)) => Err(self.error_at(
+                &self.text[offset..offset + c.len_utf8()],
+                format!("Unexpected character {:?}, expected one of {:?}", c, allowed),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Splits the line on `separator`, requiring exactly `count` fields.
+    pub fn fields(&self, separator: &str, count: usize) -> Result<Vec<&'a str>, InputError> {
+        let fields: Vec<&str> = self.text.split(separator).collect();
+        if fields.len() != count {
+            return Err(self.error(format!(
+                "Expected {} fields separated by {:?}, found {}",
+                count,
+                separator,
+                fields.len()
+            )));
+        }
+        Ok(fields)
+    }
+}
+
+/// The lines of a puzzle input, numbered as they're read.
+#[derive(Clone, Debug)]
+pub struct Lines<'a> {
+    lines: std::str::Lines<'a>,
+    index: usize,
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Line<'a>> {
+        let text = self.lines.next()?;
+        let line = Line::new(self.index, text);
+        self.index += 1;
+        Some(line)
+    }
+}
+
+impl<'a> Lines<'a> {
+    /// Reads the next line, describing what was `expected` if the input has run out.
+    pub fn expect_line(&mut self, expected: &str) -> Result<Line<'a>, InputError> {
+        let index = self.index;
+        self.next().ok_or_else(|| InputError::end_of_input(index, expected))
+    }
+}
+
+pub fn lines(input: &str) -> Lines<'_> {
+    Lines {
+        lines: input.lines(),
+        index: 0,
+    }
+}
+
+/// Every error found while parsing an input, so they can all be reported at once.
+#[derive(Debug)]
+pub struct ParseErrors(pub Vec<InputError>);
+
+impl ParseErrors {
+    pub fn in_file(self, file: &str) -> ParseErrors {
+        ParseErrors(self.0.into_iter().map(|error| error.in_file(file)).collect())
+    }
+}
+
+impl fmt::Display for ParseErrors {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let errors: Vec<String> = self.0.iter().map(InputError::to_string).collect();
+        write!(f, "{}", errors.join("\n"))
+    }
+}
+
+impl Error for ParseErrors {}
+
+/// Unwraps every result, or collects all of the errors if there were any.
+pub fn collect_results<T, I>(results: I) -> Result<Vec<T>, ParseErrors>
+where
+    I: IntoIterator<Item = Result<T, InputError>>,
+{
+    let (values, errors): (Vec<_>, Vec<_>) = results.into_iter().partition(Result::is_ok);
+    if !errors.is_empty() {
+        return Err(ParseErrors(errors.into_iter().filter_map(Result::err).collect()));
+    }
+    Ok(values.into_iter().filter_map(Result::ok).collect())
+}
+
+/// The input name that means standard input rather than a file.
+pub const STDIN: &str = "-";
+
+/// Reads the whole of an input, from standard input if `source` is `-` or else from the named file.
+pub fn read_input(source: &str) -> Result<String, Box<dyn Error>> {
+    if source == STDIN {
+        read_all(io::stdin().lock())
+            .map_err(|err| format!("Unable to read standard input: {}", err).into())
+    } else {
+        fs::read_to_string(source).map_err(|err| format!("Unable to read file {}: {}", source, err).into())
+    }
+}
+
+/// Reads everything from `reader`, such as a file, a `BufReader` or a byte string.
+pub fn read_all<R: Read>(mut reader: R) -> io::Result<String> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    Ok(input)
+}
+
+/// How to refer to an input's source in error messages.
+pub fn input_name(source: &str) -> &str {
+    if source == STDIN {
+        "<stdin>"
+    } else {
+        source
+    }
+}
+
+/// Records the input file in any input errors, so that they say where the bad line is.
+pub fn in_file(error: Box<dyn Error>, file: &str) -> Box<dyn Error> {
+    let error = match error.downcast::<InputError>() {
+        Ok(error) => return Box::new(error.in_file(file)),
+        Err(error) => error,
+    };
+    match error.downcast::<ParseErrors>() {
+        Ok(errors) => Box::new(errors.in_file(file)),
+        Err(error) => error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_a_caret_under_the_field() {
+        let line = Line::new(2, "seti 5 x 1");
+        let field = line.text.split(' ').nth(2).unwrap();
+        let error = line.parse::<usize>(field).unwrap_err().in_file("input.txt");
+        assert_eq!(
+            error.to_string(),
+            "input.txt:3:8: Unable to parse \"x\": invalid digit found in string\n\
+             3 | seti 5 x 1\n  |        ^"
+        );
+        // An error from a file named in the input keeps that file's name
+        assert!(error.in_file("main.txt").to_string().starts_with("input.txt:3:8:"));
+    }
+
+    #[test]
+    fn reads_from_any_reader() {
+        let input = read_all(io::BufReader::new(&b"1 2\n3 4\n"[..])).unwrap();
+        let fields: Vec<&str> = lines(&input).flat_map(|line| line.text.split(' ')).collect();
+        assert_eq!(fields, vec!["1", "2", "3", "4"]);
+        assert_eq!(input_name(STDIN), "<stdin>");
+    }
+
+    #[test]
+    fn reports_the_end_of_the_input() {
+        let mut lines = lines("#ip 1\n");
+        lines.expect_line("the header").unwrap();
+        let error = lines.expect_line("an instruction").unwrap_err();
+        assert_eq!(error.line_number(), 2);
+        assert_eq!(error.message(), "Expected an instruction, found the end of the input");
+    }
+}
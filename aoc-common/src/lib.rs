@@ -0,0 +1,275 @@
+extern crate regex;
+
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::io::Read;
+use std::process;
+use std::str::FromStr;
+
+mod automaton;
+mod cycle;
+mod grid;
+mod input;
+mod output;
+mod search;
+pub use automaton::{Automaton, Edges, Generations, Neighbourhood};
+pub use cycle::{brent, find_cycle, find_cycle_by_key, Cycle};
+pub use grid::{Grid, Point};
+pub use input::{
+    collect_results, in_file, input_name, lines, read_all, read_input, InputError, Line, Lines, ParseErrors, STDIN,
+};
+pub use output::{is_verbose, record, set_verbose, take_intermediates, RunResult};
+pub use search::{astar, bfs, dijkstra, Search};
+
+/// A day's puzzle, split into parsing its input and solving each of its parts.
+pub trait Solution {
+    const DAY: u32;
+    /// Whether the puzzle reads an input file, rather than being given entirely by its params.
+    const TAKES_INPUT: bool = true;
+    const PARAMS: &'static [Param] = &[];
+
+    type Input: 'static;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str, params: &Params) -> Result<Self::Input, Box<dyn Error>>;
+
+    /// Parses the input from a reader, such as standard input or a file, rather than a string.
+    fn parse_reader<R: Read>(reader: R, params: &Params) -> Result<Self::Input, Box<dyn Error>> {
+        Self::parse(&read_all(reader)?, params)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+/// A named parameter that a day takes in addition to its input.
+#[derive(Debug)]
+pub struct Param {
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+/// Parses a day's input, type-erased so that it can be handed back to the day's `SolveFn`.
+pub type ParseFn = fn(&str, &Params) -> Result<Box<dyn Any>, Box<dyn Error>>;
+
+/// Solves the given part of a day's parsed input, returning the formatted answer.
+pub type SolveFn = fn(u32, &dyn Any) -> Result<String, Box<dyn Error>>;
+
+/// A type-erased `Solution`, so that every day can be run through a common interface.
+pub struct Day {
+    pub day: u32,
+    pub takes_input: bool,
+    pub params: &'static [Param],
+    pub parse: ParseFn,
+    pub solve: SolveFn,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Day {
+        Day {
+            day: S::DAY,
+            takes_input: S::TAKES_INPUT,
+            params: S::PARAMS,
+            parse: parse::<S>,
+            solve: solve::<S>,
+        }
+    }
+
+    /// Parses the input and solves the given part, returning the formatted answer.
+    pub fn run(&self, part: u32, input: &str, params: &Params) -> Result<String, Box<dyn Error>> {
+        Ok(self.run_recording(part, input, params)?.answer)
+    }
+
+    /// Like `run`, but also returns the intermediate values that the solution recorded.
+    pub fn run_recording(&self, part: u32, input: &str, params: &Params) -> Result<Solved, Box<dyn Error>> {
+        take_intermediates();
+        let input = (self.parse)(input, params)?;
+        let answer = (self.solve)(part, &*input)?;
+        Ok(Solved {
+            answer,
+            intermediates: take_intermediates(),
+        })
+    }
+}
+
+/// The answer to a part, along with any values recorded on the way with `record`.
+#[derive(Debug)]
+pub struct Solved {
+    pub answer: String,
+    pub intermediates: BTreeMap<&'static str, i64>,
+}
+
+fn parse<S: Solution>(input: &str, params: &Params) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    Ok(Box::new(S::parse(input, params)?))
+}
+
+fn solve<S: Solution>(part: u32, input: &dyn Any) -> Result<String, Box<dyn Error>> {
+    let input = input
+        .downcast_ref::<S::Input>()
+        .ok_or_else(|| format!("Day {} was given another day's input", S::DAY))?;
+    match part {
+        1 => Ok(S::part1(input).to_string()),
+        2 => Ok(S::part2(input).to_string()),
+        _ => Err(format!("Day {} has no part {}", S::DAY, part).into()),
+    }
+}
+
+/// The parameter values given to a day, keyed by parameter name.
+#[derive(Debug, Default)]
+pub struct Params {
+    values: HashMap<String, String>,
+}
+
+impl Params {
+    pub fn new() -> Params {
+        Params::default()
+    }
+
+    pub fn insert(&mut self, name: &str, value: &str) {
+        self.values.insert(name.to_owned(), value.to_owned());
+    }
+
+    /// Parses the value of the named parameter, which must have been given.
+    pub fn get<T>(&self, name: &str) -> Result<T, ParamError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.get_opt(name)?
+            .ok_or_else(|| ParamError::Missing(name.to_owned()))
+    }
+
+    /// Parses the value of the named parameter, falling back to `default` if it wasn't given.
+    pub fn get_or<T>(&self, name: &str, default: T) -> Result<T, ParamError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        Ok(self.get_opt(name)?.unwrap_or(default))
+    }
+
+    /// Parses the value of the named parameter, if it was given.
+    pub fn get_opt<T>(&self, name: &str) -> Result<Option<T>, ParamError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.values.get(name) {
+            None => Ok(None),
+            Some(value) => value.parse::<T>().map(Some).map_err(|err| ParamError::Invalid {
+                name: name.to_owned(),
+                value: value.clone(),
+                cause: err.to_string(),
+            }),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ParamError {
+    Missing(String),
+    Invalid {
+        name: String,
+        value: String,
+        cause: String,
+    },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParamError::Missing(name) => write!(f, "Missing parameter {}", name),
+            ParamError::Invalid { name, value, cause } => write!(
+                f,
+                "Unable to parse parameter {} from {:?}: {}",
+                name, value, cause
+            ),
+        }
+    }
+}
+
+impl Error for ParamError {}
+
+/// Solves both parts of a day as a standalone binary. The positional arguments are the
+/// input filename (or `-` for standard input), if the day takes input, followed by any of
+/// its parameters in order. `--verbose` anywhere prints the solution's diagnostics to stderr,
+/// and `--format json` prints each part as a JSON object, like `aoc run --format json`.
+pub fn main<S: Solution>() {
+    let mut json = false;
+    let mut args = Vec::new();
+    let mut all_args = env::args().skip(1);
+    while let Some(arg) = all_args.next() {
+        match arg.as_str() {
+            "--verbose" => set_verbose(true),
+            "--format" => match all_args.next().as_deref() {
+                Some("text") => json = false,
+                Some("json") => json = true,
+                format => {
+                    eprintln!("Expected --format text or --format json, found {:?}", format.unwrap_or(""));
+                    process::exit(1);
+                }
+            },
+            _ => args.push(arg),
+        }
+    }
+    let mut args = args.into_iter();
+    let filename = if S::TAKES_INPUT {
+        match args.next() {
+            Some(filename) => Some(filename),
+            None => {
+                eprintln!("Please provide an input filename, or - for standard input, as argument 1");
+                process::exit(1);
+            }
+        }
+    } else {
+        None
+    };
+    let input = match &filename {
+        Some(filename) => read_input(filename).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            process::exit(1);
+        }),
+        None => String::new(),
+    };
+    let mut params = Params::new();
+    for (param, value) in S::PARAMS.iter().zip(args) {
+        params.insert(param.name, &value);
+    }
+    take_intermediates();
+    let input = S::parse(&input, &params).unwrap_or_else(|err| {
+        let err = match &filename {
+            Some(filename) => in_file(err, input_name(filename)),
+            None => err,
+        };
+        eprintln!("{}", err);
+        process::exit(1);
+    });
+    let parsed = take_intermediates();
+    let answer = S::part1(&input).to_string();
+    print_answer::<S>(json, 1, answer, &parsed);
+    let answer = S::part2(&input).to_string();
+    print_answer::<S>(json, 2, answer, &parsed);
+}
+
+/// Prints a part's answer for `main`, along with what was recorded while parsing and solving
+/// it when printing JSON.
+fn print_answer<S: Solution>(json: bool, part: u32, answer: String, parsed: &BTreeMap<&'static str, i64>) {
+    if json {
+        let mut intermediates = parsed.clone();
+        intermediates.extend(take_intermediates());
+        let result = RunResult {
+            day: S::DAY,
+            part,
+            answer,
+            intermediates,
+        };
+        println!("{}", result.to_json());
+    } else {
+        println!("Part {}: {}", part, answer);
+    }
+}
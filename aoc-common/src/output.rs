@@ -0,0 +1,74 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::Serialize;
+
+static VERBOSE: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static INTERMEDIATES: RefCell<BTreeMap<&'static str, i64>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+/// Turns on the diagnostics that solutions print with `diagnostic!`.
+pub fn set_verbose(verbose: bool) {
+    VERBOSE.store(verbose, Ordering::Relaxed);
+}
+
+pub fn is_verbose() -> bool {
+    VERBOSE.load(Ordering::Relaxed)
+}
+
+/// Prints to stderr, but only when the runner was asked to be verbose, so that
+/// diagnostics never get mixed up with the answers.
+#[macro_export]
+macro_rules! diagnostic {
+    ($($arg:tt)*) => {
+        if $crate::is_verbose() {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Records a named value worked out on the way to an answer, such as the number of rounds
+/// a simulation ran for, to be reported alongside it. Recording a name again replaces it.
+pub fn record(name: &'static str, value: i64) {
+    INTERMEDIATES.with(|intermediates| intermediates.borrow_mut().insert(name, value));
+}
+
+/// Removes and returns everything recorded on this thread so far.
+pub fn take_intermediates() -> BTreeMap<&'static str, i64> {
+    INTERMEDIATES.with(|intermediates| std::mem::take(&mut *intermediates.borrow_mut()))
+}
+
+/// The answer to one part, as printed with `--format json`.
+#[derive(Debug, Serialize)]
+pub struct RunResult {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub intermediates: BTreeMap<&'static str, i64>,
+}
+
+impl RunResult {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("A run result should always serialise")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn takes_the_latest_recorded_values() {
+        take_intermediates();
+        record("ticks", 46);
+        record("health_sum", 590);
+        record("ticks", 47);
+        let intermediates = take_intermediates();
+        assert_eq!(intermediates.into_iter().collect::<Vec<_>>(), vec![("health_sum", 590), ("ticks", 47)]);
+        assert!(take_intermediates().is_empty());
+    }
+}
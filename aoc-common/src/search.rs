@@ -0,0 +1,192 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+/// What a search found: the shortest distance to every state it settled, and the state each
+/// one was reached from along a shortest path.
+///
+/// Ties are broken deterministically. States the same distance away are settled in their `Ord`
+/// order, and a state keeps the first predecessor that reached it at its shortest distance, so
+/// the same search always finds the same paths.
+#[derive(Clone, Debug)]
+pub struct Search<S> {
+    start: S,
+    distances: HashMap<S, u64>,
+    previous: HashMap<S, S>,
+    goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    /// The goal state the search stopped at, if it found one.
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    /// How far the state is from the start, if the search reached it.
+    pub fn distance(&self, state: &S) -> Option<u64> {
+        self.distances.get(state).cloned()
+    }
+
+    /// Every state the search reached, with its distance from the start, in no particular order.
+    pub fn distances(&self) -> impl Iterator<Item = (&S, u64)> {
+        self.distances.iter().map(|(state, &distance)| (state, distance))
+    }
+
+    /// The states along a shortest path from the start to the state, including both ends.
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+        let mut path = vec![state.clone()];
+        let mut current = state;
+        while *current != self.start {
+            current = &self.previous[current];
+            path.push(current.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Searches outwards from the start one step at a time, until it settles a state for which
+/// `goal` is true or runs out of states to reach.
+pub fn bfs<S, N, I, G>(start: S, mut neighbours: N, goal: G) -> Search<S>
+where
+    S: Clone + Eq + Hash + Ord,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    run(
+        start,
+        |state| neighbours(state).into_iter().map(|next| (next, 1)).collect(),
+        |_| 0,
+        goal,
+    )
+}
+
+/// Searches outwards from the start in order of distance, where `neighbours` gives each state
+/// reachable from a state along with the cost of getting there.
+pub fn dijkstra<S, N, I, G>(start: S, neighbours: N, goal: G) -> Search<S>
+where
+    S: Clone + Eq + Hash + Ord,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    G: FnMut(&S) -> bool,
+{
+    astar(start, neighbours, |_| 0, goal)
+}
+
+/// Like `dijkstra`, but guided towards the goal by `heuristic`, which must never overestimate
+/// the remaining distance, or change by more than the cost of a step.
+pub fn astar<S, N, I, H, G>(start: S, mut neighbours: N, heuristic: H, goal: G) -> Search<S>
+where
+    S: Clone + Eq + Hash + Ord,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    H: FnMut(&S) -> u64,
+    G: FnMut(&S) -> bool,
+{
+    run(start, |state| neighbours(state).into_iter().collect(), heuristic, goal)
+}
+
+fn run<S, N, H, G>(start: S, mut neighbours: N, mut heuristic: H, mut goal: G) -> Search<S>
+where
+    S: Clone + Eq + Hash + Ord,
+    N: FnMut(&S) -> Vec<(S, u64)>,
+    H: FnMut(&S) -> u64,
+    G: FnMut(&S) -> bool,
+{
+    let mut search = Search {
+        start: start.clone(),
+        distances: HashMap::new(),
+        previous: HashMap::new(),
+        goal: None,
+    };
+    let mut best = HashMap::new();
+    best.insert(start.clone(), 0);
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((heuristic(&start), 0, start)));
+    while let Some(Reverse((_estimate, distance, state))) = queue.pop() {
+        if search.distances.contains_key(&state) {
+            continue;
+        }
+        search.distances.insert(state.clone(), distance);
+        if goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        for (next, cost) in neighbours(&state) {
+            let next_distance = distance + cost;
+            if search.distances.contains_key(&next) || best.get(&next).is_some_and(|&known| known <= next_distance) {
+                continue;
+            }
+            best.insert(next.clone(), next_distance);
+            search.previous.insert(next.clone(), state.clone());
+            queue.push(Reverse((next_distance + heuristic(&next), next_distance, next)));
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Point};
+
+    const MAZE: &str = "#######\n#S...##\n#.##..#\n#....E#\n#######";
+
+    fn open_neighbours(maze: &Grid<char>, point: &Point) -> Vec<Point> {
+        maze.neighbours4(*point)
+            .filter(|&(_point, &c)| c != '#')
+            .map(|(point, _c)| point)
+            .collect()
+    }
+
+    #[test]
+    fn finds_distances_and_the_first_path_in_reading_order() {
+        let maze = Grid::parse(MAZE, ' ', Some).unwrap();
+        let search = bfs(Point::new(1, 1), |point| open_neighbours(&maze, point), |_| false);
+        assert_eq!(search.distance(&Point::new(5, 3)), Some(6));
+        assert_eq!(search.distance(&Point::new(0, 0)), None);
+        assert_eq!(search.distances().count(), 12);
+        // Both ways round are as short, so the path goes along the top first
+        assert_eq!(
+            search.path(&Point::new(5, 3)).unwrap(),
+            vec![
+                Point::new(1, 1),
+                Point::new(2, 1),
+                Point::new(3, 1),
+                Point::new(4, 1),
+                Point::new(4, 2),
+                Point::new(5, 2),
+                Point::new(5, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn weighted_searches_agree() {
+        // Moving into the bottom row is cheap, so the path goes round that way
+        let maze = Grid::parse(MAZE, ' ', Some).unwrap();
+        let end = Point::new(5, 3);
+        let neighbours = |point: &Point| -> Vec<(Point, u64)> {
+            open_neighbours(&maze, point)
+                .into_iter()
+                .map(|next| (next, if next.y == 3 { 1 } else { 2 }))
+                .collect()
+        };
+        let plain = dijkstra(Point::new(1, 1), neighbours, |&point| point == end);
+        let guided = astar(
+            Point::new(1, 1),
+            neighbours,
+            |point| point.manhattan_distance(end) as u64,
+            |&point| point == end,
+        );
+        assert_eq!(plain.goal(), Some(&end));
+        assert_eq!(plain.distance(&end), Some(7));
+        assert_eq!(guided.distance(&end), Some(7));
+        assert_eq!(plain.path(&end), guided.path(&end));
+        assert_eq!(plain.path(&end).unwrap()[2], Point::new(1, 3));
+    }
+}
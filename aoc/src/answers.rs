@@ -0,0 +1,208 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::panic;
+use std::path::{Path, PathBuf};
+
+use aoc_common::{Day, Params};
+
+/// The name of each day's answers manifest, kept in its `input` directory.
+const MANIFEST_NAME: &str = "answers.txt";
+
+/// A recorded answer for one day and part, given an input file and/or parameters.
+#[derive(Debug)]
+pub struct Answer {
+    pub part: u32,
+    pub input: Option<String>,
+    pub params: Vec<(String, String)>,
+    pub expected: String,
+    line_number: usize,
+}
+
+/// The answers recorded for a day, one per line as tab separated columns:
+/// part, input file (or `-`), comma separated `name=value` params (or `-`) and the answer,
+/// with any backslashes, tabs and newlines in the answer escaped.
+/// Blank lines and lines starting with `#` are ignored.
+pub struct Manifest {
+    pub path: PathBuf,
+    lines: Vec<String>,
+    pub answers: Vec<Answer>,
+}
+
+#[derive(Debug)]
+pub struct ManifestError {
+    path: PathBuf,
+    line_number: usize,
+    message: String,
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.path.display(), self.line_number, self.message)
+    }
+}
+
+impl Error for ManifestError {}
+
+/// Where a day's answers manifest is, found from the workspace root rather than the current
+/// directory, so that `aoc` works from anywhere.
+pub fn manifest_path(day: u32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day-{}", day))
+        .join("input")
+        .join(MANIFEST_NAME)
+}
+
+impl Manifest {
+    pub fn read(path: PathBuf) -> Result<Manifest, Box<dyn Error>> {
+        let contents = fs::read_to_string(&path)
+            .map_err(|err| format!("Unable to read file {}: {}", path.display(), err))?;
+        let lines: Vec<String> = contents.lines().map(String::from).collect();
+        let mut answers = Vec::new();
+        for (index, line) in lines.iter().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let answer = read_answer_from_line(line, index).map_err(|message| ManifestError {
+                path: path.clone(),
+                line_number: index + 1,
+                message,
+            })?;
+            answers.push(answer);
+        }
+        Ok(Manifest {
+            path,
+            lines,
+            answers,
+        })
+    }
+
+    /// Replaces the recorded answer, ready to be written back out.
+    pub fn bless(&mut self, answer_index: usize, actual: &str) {
+        let answer = &mut self.answers[answer_index];
+        answer.expected = actual.to_owned();
+        self.lines[answer.line_number] = format_answer(answer);
+    }
+
+    pub fn write(&self) -> Result<(), Box<dyn Error>> {
+        let mut contents = self.lines.join("\n");
+        contents.push('\n');
+        fs::write(&self.path, contents)
+            .map_err(|err| format!("Unable to write file {}: {}", self.path.display(), err).into())
+    }
+
+    /// Resolves an answer's input file, which is relative to the manifest.
+    pub fn input_path(&self, answer: &Answer) -> Option<PathBuf> {
+        let directory = self.path.parent().unwrap_or_else(|| Path::new(""));
+        answer.input.as_ref().map(|input| directory.join(input))
+    }
+}
+
+fn read_answer_from_line(line: &str, index: usize) -> Result<Answer, String> {
+    let columns: Vec<&str> = line.splitn(4, '\t').collect();
+    if columns.len() != 4 {
+        return Err(format!(
+            "Expected 4 tab separated columns (part, input, params, answer), found {}",
+            columns.len()
+        ));
+    }
+    let part = columns[0]
+        .parse::<u32>()
+        .map_err(|err| format!("Unable to parse part {:?}: {}", columns[0], err))?;
+    let input = match columns[1] {
+        "-" => None,
+        input => Some(input.to_owned()),
+    };
+    let params = match columns[2] {
+        "-" => Vec::new(),
+        params => params
+            .split(',')
+            .map(|param| match param.find('=') {
+                Some(index) => Ok((param[..index].to_owned(), param[index + 1..].to_owned())),
+                None => Err(format!("Expected a parameter of the form name=value, found {:?}", param)),
+            })
+            .collect::<Result<_, _>>()?,
+    };
+    Ok(Answer {
+        part,
+        input,
+        params,
+        expected: unescape(columns[3]),
+        line_number: index,
+    })
+}
+
+fn format_answer(answer: &Answer) -> String {
+    let params: Vec<String> = answer
+        .params
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect();
+    format!(
+        "{}\t{}\t{}\t{}",
+        answer.part,
+        answer.input.as_ref().map_or("-", String::as_str),
+        if params.is_empty() { String::from("-") } else { params.join(",") },
+        escape(&answer.expected)
+    )
+}
+
+fn escape(answer: &str) -> String {
+    answer
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut result = String::new();
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => result.push('\t'),
+            Some('n') => result.push('\n'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+/// Reads the input file and parameters that a recorded answer was given.
+pub fn read_input_and_params(
+    day: &Day,
+    manifest: &Manifest,
+    answer: &Answer,
+) -> Result<(String, Params), Box<dyn Error>> {
+    let input = match manifest.input_path(answer) {
+        Some(path) => fs::read_to_string(&path)
+            .map_err(|err| format!("Unable to read file {}: {}", path.display(), err))?,
+        None => String::new(),
+    };
+    let mut params = Params::new();
+    for (name, value) in &answer.params {
+        if !day.params.iter().any(|accepted| accepted.name == name) {
+            return Err(format!("Day {} doesn't take a parameter named {}", day.day, name).into());
+        }
+        params.insert(name, value);
+    }
+    Ok((input, params))
+}
+
+/// Runs a day against a recorded answer, returning what the solution produced.
+/// Panics in the solution are caught and reported as errors.
+pub fn solve(day: &Day, manifest: &Manifest, answer: &Answer) -> Result<String, Box<dyn Error>> {
+    let (input, params) = read_input_and_params(day, manifest, answer)?;
+    match panic::catch_unwind(|| day.run(answer.part, &input, &params)) {
+        Ok(result) => result.map_err(|err| match manifest.input_path(answer) {
+            Some(path) => aoc_common::in_file(err, &path.display().to_string()),
+            None => err,
+        }),
+        Err(_) => Err("The solution panicked".into()),
+    }
+}
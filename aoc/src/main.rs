@@ -0,0 +1,522 @@
+extern crate clap;
+extern crate serde;
+extern crate serde_json;
+
+use std::fs;
+use std::io;
+use std::process;
+
+use aoc_common::{Day, Params, RunResult};
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+use serde::Serialize;
+
+mod allocator;
+mod answers;
+mod parts;
+mod report;
+use allocator::PeakAllocator;
+use answers::Manifest;
+use parts::DAYS;
+
+#[global_allocator]
+static ALLOCATOR: PeakAllocator = PeakAllocator;
+
+fn main() {
+    let matches = App::new("aoc")
+        .about("Runs Advent of Code 2018 solutions")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .arg(
+            Arg::with_name("verbose")
+                .long("verbose")
+                .global(true)
+                .help("Prints the solutions' diagnostics to stderr"),
+        )
+        .subcommand(SubCommand::with_name("list").about("Lists every day and its parameters"))
+        .subcommand(
+            SubCommand::with_name("run")
+                .about("Runs a single day and part")
+                .arg(
+                    Arg::with_name("day")
+                        .long("day")
+                        .takes_value(true)
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("part")
+                        .long("part")
+                        .takes_value(true)
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("input")
+                        .long("input")
+                        .takes_value(true)
+                        .help("The puzzle input file, or - to read it from standard input"),
+                )
+                .arg(
+                    Arg::with_name("param")
+                        .long("param")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1)
+                        .value_name("NAME=VALUE")
+                        .help("A parameter for the part, see `aoc list`"),
+                )
+                .arg(
+                    Arg::with_name("format")
+                        .long("format")
+                        .takes_value(true)
+                        .possible_values(&["text", "json"])
+                        .default_value("text")
+                        .help("Print just the answer, or a JSON object with the answer and any recorded values"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("check")
+                .about("Checks every day against the answers recorded in its input/answers.txt")
+                .arg(
+                    Arg::with_name("day")
+                        .long("day")
+                        .takes_value(true)
+                        .help("Only check this day"),
+                )
+                .arg(
+                    Arg::with_name("bless")
+                        .long("bless")
+                        .help("Re-record any answers that don't match"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("bench-report")
+                .about("Times parsing and solving each part once, on the last input recorded for it")
+                .arg(
+                    Arg::with_name("day")
+                        .long("day")
+                        .takes_value(true)
+                        .help("Only time this day"),
+                )
+                .arg(
+                    Arg::with_name("json")
+                        .long("json")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .help("Also write the report to this file as JSON"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("elfcode")
+                .about("Tools for the ElfCode programs from days 16, 19 and 21")
+                .setting(AppSettings::SubcommandRequiredElseHelp)
+                .subcommand(
+                    SubCommand::with_name("debug")
+                        .about("Steps through a program interactively, see `help` once it's running")
+                        .arg(elfcode_input_arg())
+                        .arg(elfcode_registers_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("decompile")
+                        .about("Prints a program as pseudocode, with its jumps turned into loops and ifs")
+                        .arg(elfcode_input_arg())
+                        .arg(elfcode_registers_arg()),
+                )
+                .subcommand(
+                    SubCommand::with_name("profile")
+                        .about("Runs a program, counting how often each instruction and loop runs")
+                        .arg(elfcode_input_arg())
+                        .arg(elfcode_registers_arg())
+                        .arg(
+                            Arg::with_name("register-0")
+                                .long("register-0")
+                                .takes_value(true)
+                                .default_value("0")
+                                .help("The value register 0 starts with"),
+                        )
+                        .arg(
+                            Arg::with_name("limit")
+                                .long("limit")
+                                .takes_value(true)
+                                .help("Stop after running this many instructions"),
+                        )
+                        .arg(
+                            Arg::with_name("format")
+                                .long("format")
+                                .takes_value(true)
+                                .possible_values(&["text", "json"])
+                                .default_value("text")
+                                .help("Print the profile as tables, or as a JSON object"),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("disassemble")
+                        .about("Prints day 16's program as ElfCode, once its opcodes are worked out")
+                        .arg(
+                            Arg::with_name("input")
+                                .long("input")
+                                .takes_value(true)
+                                .required(true)
+                                .help("The day 16 puzzle input"),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("samples")
+                        .about("Shows which ops each of day 16's samples behaves like")
+                        .arg(
+                            Arg::with_name("input")
+                                .long("input")
+                                .takes_value(true)
+                                .required(true)
+                                .help("The day 16 puzzle input"),
+                        )
+                        .arg(
+                            Arg::with_name("table")
+                                .long("table")
+                                .takes_value(true)
+                                .possible_values(&["samples", "matrix"])
+                                .default_value("samples")
+                                .help("List every sample, or count the samples each op fits for each opcode"),
+                        )
+                        .arg(
+                            Arg::with_name("format")
+                                .long("format")
+                                .takes_value(true)
+                                .possible_values(&["text", "csv"])
+                                .default_value("text"),
+                        ),
+                ),
+        )
+        .get_matches();
+    aoc_common::set_verbose(matches.is_present("verbose"));
+
+    match matches.subcommand() {
+        ("list", Some(_)) => list(),
+        ("run", Some(run_matches)) => {
+            if let Err(err) = run(run_matches) {
+                eprintln!("{}", err);
+                process::exit(1);
+            }
+        }
+        ("check", Some(check_matches)) => match check(check_matches) {
+            Ok(true) => (),
+            Ok(false) => process::exit(1),
+            Err(err) => {
+                eprintln!("{}", err);
+                process::exit(1);
+            }
+        },
+        ("bench-report", Some(report_matches)) => match bench_report(report_matches) {
+            Ok(true) => (),
+            Ok(false) => process::exit(1),
+            Err(err) => {
+                eprintln!("{}", err);
+                process::exit(1);
+            }
+        },
+        ("elfcode", Some(elfcode_matches)) => {
+            if let Err(err) = elfcode(elfcode_matches) {
+                eprintln!("{}", err);
+                process::exit(1);
+            }
+        }
+        _ => unreachable!("a subcommand is required"),
+    }
+}
+
+fn list() {
+    for day in DAYS {
+        let params: Vec<String> = day
+            .params
+            .iter()
+            .map(|param| format!("--param {}=<{}>", param.name, param.description))
+            .collect();
+        println!(
+            "Day {:>2}  {:<8} {}",
+            day.day,
+            if day.takes_input { "input" } else { "no input" },
+            params.join(" ")
+        );
+    }
+}
+
+fn run(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    let day_number = matches.value_of("day").unwrap().parse::<u32>()?;
+    let part = matches.value_of("part").unwrap().parse::<u32>()?;
+    let day = find_day(day_number).ok_or_else(|| format!("There is no solution for day {}", day_number))?;
+
+    let input = match (day.takes_input, matches.value_of("input")) {
+        (true, Some(filename)) => aoc_common::read_input(filename)?,
+        (true, None) => return Err(format!("Day {} needs an --input file", day_number).into()),
+        (false, Some(_)) => return Err(format!("Day {} doesn't take an input file", day_number).into()),
+        (false, None) => String::new(),
+    };
+
+    let mut params = Params::new();
+    for param in matches.values_of("param").into_iter().flatten() {
+        let (name, value) = match param.find('=') {
+            Some(index) => (&param[..index], &param[index + 1..]),
+            None => return Err(format!("Expected a parameter of the form name=value, found {:?}", param).into()),
+        };
+        if !day.params.iter().any(|accepted| accepted.name == name) {
+            return Err(format!("Day {} doesn't take a parameter named {}", day_number, name).into());
+        }
+        params.insert(name, value);
+    }
+
+    let solved = day
+        .run_recording(part, &input, &params)
+        .map_err(|err| match matches.value_of("input") {
+            Some(filename) => aoc_common::in_file(err, aoc_common::input_name(filename)),
+            None => err,
+        })?;
+    match matches.value_of("format") {
+        Some("json") => println!(
+            "{}",
+            RunResult {
+                day: day_number,
+                part,
+                answer: solved.answer,
+                intermediates: solved.intermediates,
+            }
+            .to_json()
+        ),
+        _ => println!("{}", solved.answer),
+    }
+    Ok(())
+}
+
+fn read_manual(filename: &str) -> Result<day_16::Manual, Box<dyn std::error::Error>> {
+    day_16::Manual::read(&aoc_common::read_input(filename)?)
+        .map_err(|err| err.in_file(aoc_common::input_name(filename)).into())
+}
+
+fn samples(matches: &ArgMatches, filename: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let manual = read_manual(filename)?;
+    let report = day_16::SampleReport::new(&manual);
+    let output = match (matches.value_of("table"), matches.value_of("format")) {
+        (Some("matrix"), Some("csv")) => report.matrix_csv(),
+        (Some("matrix"), _) => report.matrix_table(),
+        (_, Some("csv")) => report.samples_csv(),
+        _ => report.samples_table(),
+    };
+    print!("{}", output);
+    Ok(())
+}
+
+fn disassemble(filename: &str) -> Result<(), Box<dyn std::error::Error>> {
+    print!("{}", read_manual(filename)?.disassemble()?);
+    Ok(())
+}
+
+/// The result of `aoc elfcode profile --format json`.
+#[derive(Serialize)]
+struct ProfileReport {
+    executed: u64,
+    halted: bool,
+    instructions: Vec<InstructionProfile>,
+    loops: Vec<LoopProfile>,
+    registers: Vec<RegisterRange>,
+}
+
+#[derive(Serialize)]
+struct InstructionProfile {
+    address: usize,
+    instruction: String,
+    count: u64,
+}
+
+#[derive(Serialize)]
+struct LoopProfile {
+    start: usize,
+    end: usize,
+    iterations: u64,
+    executed: u64,
+}
+
+#[derive(Serialize)]
+struct RegisterRange {
+    register: usize,
+    min: usize,
+    max: usize,
+}
+
+impl ProfileReport {
+    fn new(profile: &elfcode::Profile) -> ProfileReport {
+        ProfileReport {
+            executed: profile.executed,
+            halted: profile.halted,
+            instructions: profile
+                .instructions
+                .iter()
+                .zip(&profile.counts)
+                .enumerate()
+                .map(|(address, (instruction, &count))| InstructionProfile {
+                    address,
+                    instruction: instruction.to_string(),
+                    count,
+                })
+                .collect(),
+            loops: profile
+                .loops
+                .iter()
+                .map(|found| LoopProfile {
+                    start: found.start,
+                    end: found.end,
+                    iterations: found.iterations,
+                    executed: found.executed,
+                })
+                .collect(),
+            registers: profile
+                .register_ranges
+                .iter()
+                .enumerate()
+                .map(|(register, &(min, max))| RegisterRange { register, min, max })
+                .collect(),
+        }
+    }
+}
+
+/// Returns whether every answer matched, or was blessed.
+fn check(matches: &ArgMatches) -> Result<bool, Box<dyn std::error::Error>> {
+    let bless = matches.is_present("bless");
+    let days = selected_days(matches)?;
+
+    let (mut passed, mut failed, mut blessed) = (0, 0, 0);
+    for day in days {
+        let mut manifest = Manifest::read(answers::manifest_path(day.day))?;
+        let mut changed = false;
+        for index in 0..manifest.answers.len() {
+            let answer = &manifest.answers[index];
+            let description = format!(
+                "Day {:>2} part {} {}",
+                day.day,
+                answer.part,
+                answer.input.as_ref().map_or("(no input)", String::as_str)
+            );
+            match answers::solve(day, &manifest, answer) {
+                Ok(ref actual) if *actual == answer.expected => passed += 1,
+                Ok(actual) => {
+                    if bless {
+                        println!("{}: blessed {:?} (was {:?})", description, actual, answer.expected);
+                        manifest.bless(index, &actual);
+                        changed = true;
+                        blessed += 1;
+                    } else {
+                        println!("{}: expected {:?}, found {:?}", description, answer.expected, actual);
+                        failed += 1;
+                    }
+                }
+                Err(err) => {
+                    println!("{}: failed: {}", description, err);
+                    failed += 1;
+                }
+            }
+        }
+        if changed {
+            manifest.write()?;
+        }
+    }
+
+    if bless {
+        println!("{} passed, {} blessed, {} failed", passed, blessed, failed);
+    } else {
+        println!("{} passed, {} failed", passed, failed);
+    }
+    Ok(failed == 0)
+}
+
+/// Returns whether every day could be timed.
+fn bench_report(matches: &ArgMatches) -> Result<bool, Box<dyn std::error::Error>> {
+    let days = selected_days(matches)?;
+    let mut timings = Vec::new();
+    let mut failed = false;
+    for day in days {
+        match report::time_day(day) {
+            Ok(day_timings) => timings.extend(day_timings),
+            Err(err) => {
+                eprintln!("Day {:>2}: failed: {}", day.day, err);
+                failed = true;
+            }
+        }
+    }
+
+    report::sort_slowest_first(&mut timings);
+    println!("{}", report::format_table(&timings));
+    if let Some(filename) = matches.value_of("json") {
+        fs::write(filename, serde_json::to_string_pretty(&timings)?)
+            .map_err(|err| format!("Unable to write file {}: {}", filename, err))?;
+    }
+    Ok(!failed)
+}
+
+fn elfcode_input_arg() -> Arg<'static, 'static> {
+    Arg::with_name("input")
+        .long("input")
+        .takes_value(true)
+        .required(true)
+        .help("The program file")
+}
+
+fn elfcode_registers_arg() -> Arg<'static, 'static> {
+    Arg::with_name("registers")
+        .long("registers")
+        .takes_value(true)
+        .default_value("6")
+        .help("How many registers the machine has")
+}
+
+fn elfcode(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    let (command, command_matches) = match matches.subcommand() {
+        (command, Some(command_matches)) => (command, command_matches),
+        _ => unreachable!("a subcommand is required"),
+    };
+    let filename = command_matches.value_of("input").unwrap();
+    match command {
+        "samples" => return samples(command_matches, filename),
+        "disassemble" => return disassemble(filename),
+        _ => {}
+    }
+    let registers = command_matches.value_of("registers").unwrap().parse::<usize>()?;
+    if registers > elfcode::MAX_REGISTERS {
+        return Err(format!("A machine can have at most {} registers", elfcode::MAX_REGISTERS).into());
+    }
+    let program = elfcode::Program::parse(&aoc_common::read_input(filename)?, registers)
+        .map_err(|err| err.in_file(aoc_common::input_name(filename)))?;
+    match command {
+        "debug" => {
+            let stdin = io::stdin();
+            elfcode::Debugger::new(&program).repl(stdin.lock(), io::stdout())?;
+        }
+        "decompile" => print!("{}", elfcode::decompile(&program)),
+        "profile" => {
+            let mut machine = elfcode::Machine::new(registers);
+            if registers > 0 {
+                machine.registers_mut()[0] = command_matches.value_of("register-0").unwrap().parse()?;
+            }
+            let limit = match command_matches.value_of("limit") {
+                Some(limit) => Some(limit.parse::<u64>()?),
+                None => None,
+            };
+            let profile = elfcode::Profile::run(&program, &mut machine, limit);
+            match command_matches.value_of("format") {
+                Some("json") => println!("{}", serde_json::to_string_pretty(&ProfileReport::new(&profile))?),
+                _ => print!("{}", profile),
+            }
+        }
+        _ => unreachable!("unknown elfcode subcommand"),
+    }
+    Ok(())
+}
+
+/// The day given by `--day`, or every day if there wasn't one.
+fn selected_days(matches: &ArgMatches) -> Result<Vec<&'static Day>, Box<dyn std::error::Error>> {
+    match matches.value_of("day") {
+        Some(day_number) => {
+            let day_number = day_number.parse::<u32>()?;
+            Ok(vec![find_day(day_number).ok_or_else(|| format!("There is no solution for day {}", day_number))?])
+        }
+        None => Ok(DAYS.iter().collect()),
+    }
+}
+
+fn find_day(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|candidate| candidate.day == day)
+}
@@ -0,0 +1,30 @@
+use aoc_common::Day;
+
+/// Every day the runner can dispatch to, in order.
+pub const DAYS: &[Day] = &[
+    Day::of::<day_1::Day1>(),
+    Day::of::<day_2::Day2>(),
+    Day::of::<day_3::Day3>(),
+    Day::of::<day_4::Day4>(),
+    Day::of::<day_5::Day5>(),
+    Day::of::<day_6::Day6>(),
+    Day::of::<day_7::Day7>(),
+    Day::of::<day_8::Day8>(),
+    Day::of::<day_9::Day9>(),
+    Day::of::<day_10::Day10>(),
+    Day::of::<day_11::Day11>(),
+    Day::of::<day_12::Day12>(),
+    Day::of::<day_13::Day13>(),
+    Day::of::<day_14::Day14>(),
+    Day::of::<day_15::Day15>(),
+    Day::of::<day_16::Day16>(),
+    Day::of::<day_17::Day17>(),
+    Day::of::<day_18::Day18>(),
+    Day::of::<day_19::Day19>(),
+    Day::of::<day_20::Day20>(),
+    Day::of::<day_21::Day21>(),
+    Day::of::<day_22::Day22>(),
+    Day::of::<day_23::Day23>(),
+    Day::of::<day_24::Day24>(),
+    Day::of::<day_25::Day25>(),
+];
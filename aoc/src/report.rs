@@ -0,0 +1,132 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Instant;
+
+use aoc_common::Day;
+use serde::Serialize;
+
+use crate::allocator;
+use crate::answers::{self, Manifest};
+
+/// How long one stage of a run took, and the most memory it had allocated at once.
+#[derive(Debug, Serialize)]
+pub struct Measurement {
+    pub nanoseconds: u64,
+    pub peak_bytes: usize,
+}
+
+/// The measurements from parsing a day's input and solving one of its parts.
+#[derive(Debug, Serialize)]
+pub struct Timing {
+    pub day: u32,
+    pub part: u32,
+    pub input: Option<String>,
+    pub params: BTreeMap<String, String>,
+    pub parse: Measurement,
+    pub solve: Measurement,
+}
+
+impl Timing {
+    fn total_nanoseconds(&self) -> u64 {
+        self.parse.nanoseconds + self.solve.nanoseconds
+    }
+}
+
+fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Measurement) {
+    let start = Instant::now();
+    let (result, peak_bytes) = allocator::measure_peak(f);
+    let nanoseconds = start.elapsed().as_nanos() as u64;
+    (result, Measurement { nanoseconds, peak_bytes })
+}
+
+fn catch_panic<T, F>(f: F) -> Result<T, Box<dyn Error>>
+where
+    F: FnOnce() -> Result<T, Box<dyn Error>>,
+{
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|_| Err("The solution panicked".into()))
+}
+
+/// Times each part of a day once, using the last answer recorded for that part,
+/// which is the real puzzle input when the day has one.
+pub fn time_day(day: &Day) -> Result<Vec<Timing>, Box<dyn Error>> {
+    let manifest = Manifest::read(answers::manifest_path(day.day))?;
+    let mut timings = Vec::new();
+    for part in 1..=2 {
+        let answer = match manifest.answers.iter().rev().find(|answer| answer.part == part) {
+            Some(answer) => answer,
+            None => continue,
+        };
+        let (input, params) = answers::read_input_and_params(day, &manifest, answer)?;
+        let input_path = manifest.input_path(answer).map(|path| path.display().to_string());
+        let (parsed, parse) = measure(|| catch_panic(|| (day.parse)(&input, &params)));
+        let parsed = parsed.map_err(|err| match &input_path {
+            Some(path) => aoc_common::in_file(err, path),
+            None => err,
+        })?;
+        let (solved, solve) = measure(|| catch_panic(|| (day.solve)(part, &*parsed)));
+        solved?;
+        timings.push(Timing {
+            day: day.day,
+            part,
+            input: input_path,
+            params: answer.params.iter().cloned().collect(),
+            parse,
+            solve,
+        });
+    }
+    Ok(timings)
+}
+
+/// Sorts the timings so that the slowest come first.
+pub fn sort_slowest_first(timings: &mut [Timing]) {
+    timings.sort_by_key(|timing| std::cmp::Reverse(timing.total_nanoseconds()));
+}
+
+pub fn format_table(timings: &[Timing]) -> String {
+    let mut lines = vec![format!(
+        "{:>3} {:>4}  {:>12} {:>12} {:>12}  {:>10} {:>10}  {}",
+        "Day", "Part", "Parse", "Solve", "Total", "Parse mem", "Solve mem", "Input"
+    )];
+    for timing in timings {
+        let input = match &timing.input {
+            Some(input) => input.clone(),
+            None => timing
+                .params
+                .iter()
+                .map(|(name, value)| format!("{}={}", name, value))
+                .collect::<Vec<_>>()
+                .join(","),
+        };
+        lines.push(format!(
+            "{:>3} {:>4}  {:>12} {:>12} {:>12}  {:>10} {:>10}  {}",
+            timing.day,
+            timing.part,
+            format_duration(timing.parse.nanoseconds),
+            format_duration(timing.solve.nanoseconds),
+            format_duration(timing.total_nanoseconds()),
+            format_bytes(timing.parse.peak_bytes),
+            format_bytes(timing.solve.peak_bytes),
+            input
+        ));
+    }
+    lines.join("\n")
+}
+
+fn format_duration(nanoseconds: u64) -> String {
+    format!("{:.3} ms", nanoseconds as f64 / 1_000_000.0)
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 3] = ["KiB", "MiB", "GiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
@@ -0,0 +1,40 @@
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+/// The days that check in about a second even in debug, so that a plain `cargo test` catches
+/// answers broken by a refactor.
+const FAST_DAYS: [u32; 18] = [1, 2, 3, 4, 6, 7, 8, 9, 10, 12, 14, 16, 17, 19, 20, 22, 23, 25];
+
+fn check(args: &[&str], directory: &Path) {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("check")
+        .args(args)
+        .current_dir(directory)
+        .output()
+        .expect("Unable to run aoc check");
+    assert!(
+        output.status.success(),
+        "aoc check {} found answers that don't match:\n{}{}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn fast_days_match_their_recorded_answers() {
+    // Run from outside the workspace, as the manifests should be found from anywhere
+    for day in FAST_DAYS.iter() {
+        check(&["--day", &day.to_string()], &env::temp_dir());
+    }
+}
+
+// Every day takes about a minute in release and far longer in debug, so run this with
+// `cargo test --release -p aoc -- --ignored`
+#[test]
+#[ignore]
+fn every_recorded_answer_matches() {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    check(&[], workspace);
+}
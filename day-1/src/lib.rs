@@ -0,0 +1,67 @@
+extern crate itertools;
+
+use std::collections::HashSet;
+use std::error::Error;
+
+use itertools::FoldWhile::{Continue, Done};
+use itertools::Itertools;
+
+use aoc_common::{Params, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+  const DAY: u32 = 1;
+
+  type Input = Vec<i32>;
+  type Part1 = i32;
+  type Part2 = i32;
+
+  fn parse(input: &str, _params: &Params) -> Result<Vec<i32>, Box<dyn Error>> {
+    Ok(aoc_common::collect_results(aoc_common::lines(input).map(|line| line.parse::<i32>(line.text)))?)
+  }
+
+  fn part1(frequency_changes: &Vec<i32>) -> i32 {
+    frequency_changes.iter().sum()
+  }
+
+  fn part2(frequency_changes: &Vec<i32>) -> i32 {
+    const INITIAL_FREQUENCY: i32 = 0;
+    let result = frequency_changes.iter().cycle()
+      .fold_while((INITIAL_FREQUENCY, HashSet::from([INITIAL_FREQUENCY])), | (frequency, mut frequencies_seen), change| {
+        let new_frequency = frequency + change;
+        if frequencies_seen.contains(&new_frequency) {
+          Done((new_frequency, frequencies_seen))
+        } else {
+          frequencies_seen.insert(new_frequency);
+          Continue((new_frequency, frequencies_seen))
+        }
+      }).into_inner();
+
+    result.0
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn read(changes: &str) -> Vec<i32> {
+    Day1::parse(&changes.replace(", ", "\n"), &Params::new()).unwrap()
+  }
+
+  #[test]
+  fn example_frequencies() {
+    let changes = Day1::parse(include_str!("../fixtures/example.txt"), &Params::new()).unwrap();
+    assert_eq!(changes, vec![1, -2, 3, 1]);
+    assert_eq!(Day1::part1(&changes), 3);
+    assert_eq!(Day1::part2(&changes), 2);
+
+    assert_eq!(Day1::part1(&read("+1, +1, -2")), 0);
+    assert_eq!(Day1::part1(&read("-1, -2, -3")), -6);
+    assert_eq!(Day1::part2(&read("+1, -1")), 0);
+    assert_eq!(Day1::part2(&read("+3, +3, +4, -2, -4")), 10);
+    assert_eq!(Day1::part2(&read("-6, +3, +8, +5, -6")), 5);
+    assert_eq!(Day1::part2(&read("+7, +7, -2, -7, -4")), 14);
+  }
+}
@@ -0,0 +1,177 @@
+#[macro_use]
+extern crate lazy_static;
+extern crate regex;
+
+use std::collections::HashSet;
+use std::error::Error;
+
+use regex::Regex;
+
+use aoc_common::{InputError, Line, Params, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type Input = Vec<LightPoint>;
+    type Part1 = String;
+    type Part2 = i32;
+
+    fn parse(input: &str, _params: &Params) -> Result<Vec<LightPoint>, Box<dyn Error>> {
+        let light_points = aoc_common::collect_results(aoc_common::lines(input).map(read_light_point_from_line))?;
+        if light_points.is_empty() {
+            return Err("The input has no points of light in it".into());
+        }
+        Ok(light_points)
+    }
+
+    fn part1(light_points: &Vec<LightPoint>) -> String {
+        let (_second, message) = find_message(light_points);
+        format_state(&message)
+    }
+
+    fn part2(light_points: &Vec<LightPoint>) -> i32 {
+        let (second, _message) = find_message(light_points);
+        second
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LightPoint {
+    velocity: Velocity,
+    position: Position,
+}
+
+#[derive(Debug, Clone)]
+struct Velocity {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, Clone)]
+struct Position {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug)]
+struct Bounds {
+    top: i32,
+    left: i32,
+    bottom: i32,
+    right: i32,
+}
+
+impl Bounds {
+    fn height(&self) -> i32 {
+        self.bottom - self.top
+    }
+}
+
+fn read_light_point_from_line(line: Line) -> Result<LightPoint, InputError> {
+    lazy_static! {
+        static ref light_point_regex: Regex =
+            Regex::new(r"position=<\s*(-?\d+),\s*(-?\d+)>\s*velocity=<\s*(-?\d+),\s*(-?\d+)>")
+                .unwrap();
+    }
+
+    let captures = line.captures(
+        &light_point_regex,
+        "a light point like \"position=< 9,  1> velocity=< 0,  2>\"",
+    )?;
+    Ok(LightPoint {
+        position: Position {
+            x: line.parse::<i32>(&captures[1])?,
+            y: line.parse::<i32>(&captures[2])?,
+        },
+        velocity: Velocity {
+            x: line.parse::<i32>(&captures[3])?,
+            y: line.parse::<i32>(&captures[4])?,
+        },
+    })
+}
+
+// Assumes the points converge to spell the message, then drift apart again
+fn find_message(light_points: &[LightPoint]) -> (i32, Vec<LightPoint>) {
+    let mut current_second = 0;
+    let mut current_state = light_points.to_vec();
+    loop {
+        let next_state = increment_state(current_state.clone(), 1);
+        if bounds_from_light_points(&next_state).height()
+            > bounds_from_light_points(&current_state).height()
+        {
+            return (current_second, current_state);
+        }
+        current_state = next_state;
+        current_second += 1;
+    }
+}
+
+fn format_state(light_points: &[LightPoint]) -> String {
+    let bounds = bounds_from_light_points(light_points);
+    let lit_positions: HashSet<(i32, i32)> = light_points
+        .iter()
+        .map(|light_point| (light_point.position.x, light_point.position.y))
+        .collect();
+
+    let mut result = String::new();
+    for y in bounds.top..bounds.bottom + 1 {
+        for x in bounds.left..bounds.right + 1 {
+            if lit_positions.contains(&(x, y)) {
+                result.push('#');
+            } else {
+                result.push('.');
+            }
+        }
+        result.push('\n');
+    }
+    result
+}
+
+fn increment_state(light_points: Vec<LightPoint>, amount: i32) -> Vec<LightPoint> {
+    light_points
+        .into_iter()
+        .map(|light_point| LightPoint {
+            position: Position {
+                x: light_point.position.x + amount * light_point.velocity.x,
+                y: light_point.position.y + amount * light_point.velocity.y,
+            },
+            ..light_point
+        })
+        .collect()
+}
+
+fn bounds_from_light_points(light_points: &[LightPoint]) -> Bounds {
+    light_points.iter().fold(
+        Bounds {
+            top: i32::MAX,
+            left: i32::MAX,
+            bottom: i32::MIN,
+            right: i32::MIN,
+        },
+        |bounds, light_point| Bounds {
+            top: i32::min(bounds.top, light_point.position.y),
+            left: i32::min(bounds.left, light_point.position.x),
+            bottom: i32::max(bounds.bottom, light_point.position.y),
+            right: i32::max(bounds.right, light_point.position.x),
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_message() {
+        let light_points = Day10::parse(include_str!("../fixtures/example.txt"), &Params::new()).unwrap();
+        assert_eq!(bounds_from_light_points(&light_points).height(), 15);
+        let after_3 = increment_state(light_points.clone(), 3);
+        assert_eq!(bounds_from_light_points(&after_3).height(), 7);
+        assert_eq!(format_state(&after_3), include_str!("../fixtures/example-after-3.txt"));
+        let (second, message) = find_message(&light_points);
+        assert_eq!(second, 3);
+        assert_eq!(format_state(&message), format_state(&after_3));
+    }
+}
@@ -1,20 +1,44 @@
 use std::collections::HashMap;
-use std::env;
-
-fn main() -> Result<(), Box<std::error::Error>> {
-    const GRID_SIDE_SIZE: usize = 300;
-
-    let args: Vec<String> = env::args().collect();
-    let serial_number = args[1].parse::<i32>()?;
-    let fuel_cell_power_grid =
-        generate_fuel_cell_grid(GRID_SIDE_SIZE, GRID_SIDE_SIZE, serial_number);
-    let sum_table = generate_sum_table_grid(&fuel_cell_power_grid);
-    let result = get_highest_power_region(&sum_table);
-    println!("{:?}", result);
-    Ok(())
+use std::error::Error;
+
+use aoc_common::{Param, Params, Solution};
+
+const GRID_SIDE_SIZE: usize = 300;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const TAKES_INPUT: bool = false;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "serial",
+        description: "the grid serial number",
+    }];
+
+    type Input = Grid;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(_input: &str, params: &Params) -> Result<Grid, Box<dyn Error>> {
+        let serial_number: i32 = params.get("serial")?;
+        let fuel_cell_power_grid =
+            generate_fuel_cell_grid(GRID_SIDE_SIZE, GRID_SIDE_SIZE, serial_number);
+        Ok(generate_sum_table_grid(&fuel_cell_power_grid))
+    }
+
+    fn part1(sum_table: &Grid) -> String {
+        let (x, y, _width, _height, _total) =
+            get_highest_power_for_region_of_size(sum_table, 3, 3);
+        format!("{},{}", x, y)
+    }
+
+    fn part2(sum_table: &Grid) -> String {
+        let (x, y, size, _height, _total) = get_highest_power_region(sum_table);
+        format!("{},{},{}", x, y, size)
+    }
 }
 
-struct Grid {
+pub struct Grid {
     width: usize,
     height: usize,
     values: HashMap<(usize, usize), i32>,
@@ -65,7 +89,7 @@ fn generate_sum_table_grid(grid: &Grid) -> Grid {
 fn get_highest_power_region(sum_table: &Grid) -> (usize, usize, usize, usize, i32) {
     (1..sum_table.width + 1)
         .map(|region_size| {
-            get_highest_power_for_region_of_size(&sum_table, region_size, region_size)
+            get_highest_power_for_region_of_size(sum_table, region_size, region_size)
         })
         .max_by_key(|(_x, _y, _width, _height, total)| *total)
         .unwrap()
@@ -76,7 +100,7 @@ fn get_highest_power_for_region_of_size(
     region_width: usize,
     region_height: usize,
 ) -> (usize, usize, usize, usize, i32) {
-    let mut max_power = (0, 0, 0, 0, std::i32::MIN);
+    let mut max_power = (0, 0, 0, 0, i32::MIN);
 
     for y in 1..sum_table.height - region_height {
         for x in 1..sum_table.width - region_width {
@@ -135,6 +159,20 @@ mod tests {
         assert_eq!(sum_table.values.get(&(3, 3)).unwrap(), &45);
     }
 
+    #[test]
+    fn max_3x3_power_calculations() {
+        let grid = generate_fuel_cell_grid(300, 300, 18);
+        assert_eq!(
+            get_highest_power_for_region_of_size(&generate_sum_table_grid(&grid), 3, 3),
+            (33, 45, 3, 3, 29)
+        );
+        let grid = generate_fuel_cell_grid(300, 300, 42);
+        assert_eq!(
+            get_highest_power_for_region_of_size(&generate_sum_table_grid(&grid), 3, 3),
+            (21, 61, 3, 3, 30)
+        );
+    }
+
     #[test]
     fn max_power_calculations() {
         let grid = generate_fuel_cell_grid(300, 300, 18);
@@ -0,0 +1,150 @@
+#[macro_use]
+extern crate lazy_static;
+extern crate regex;
+
+use std::error::Error;
+use std::iter::Iterator;
+use std::ops::Range;
+
+use aoc_common::{Automaton, Edges, Grid, InputError, Line, Lines, Neighbourhood, Param, ParseErrors, Params, Point, Solution};
+use regex::Regex;
+
+pub struct Day12;
+
+pub struct Plants {
+    initial_state: Grid<bool>,
+    rules: Rules,
+    generations: Option<u64>,
+}
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "generations",
+        description: "the number of generations (default 20 for part 1, 50000000000 for part 2)",
+    }];
+
+    type Input = Plants;
+    type Part1 = i32;
+    type Part2 = i64;
+
+    fn parse(input: &str, params: &Params) -> Result<Plants, Box<dyn Error>> {
+        let generations = params.get_opt("generations")?;
+        let mut lines = aoc_common::lines(input);
+        let first_line = lines.expect_line("the initial state")?;
+        let initial_state = read_initial_state_from_line(first_line)?;
+        //Read empty line
+        lines.next();
+        let rules = read_rules_from_lines(lines)?;
+        Ok(Plants {
+            initial_state,
+            rules,
+            generations,
+        })
+    }
+
+    fn part1(plants: &Plants) -> i32 {
+        let state_number = plants.generations.unwrap_or(20) as usize;
+        let final_state = automaton(plants.rules)
+            .run(plants.initial_state.clone())
+            .nth(state_number)
+            .unwrap();
+        plant_sum(&final_state) as i32
+    }
+
+    fn part2(plants: &Plants) -> i64 {
+        let target_state = plants.generations.unwrap_or(50_000_000_000);
+        // The plants settle into a pattern that repeats while it moves along. The automaton
+        // trims each generation to fit its plants, so look for a repeat of just the pots
+        let automaton = automaton(plants.rules);
+        let states = automaton.run(plants.initial_state.clone());
+        let (cycle, history) = aoc_common::find_cycle_by_key(states, |state| state.cells().cloned().collect::<Vec<bool>>())
+            .expect("The plants never stop changing");
+        aoc_common::diagnostic!("The pattern repeats every {} generations from generation {}", cycle.period, cycle.start);
+        aoc_common::record("cycle_start", cycle.start as i64);
+        let sums: Vec<i64> = history.iter().map(plant_sum).collect();
+        cycle.extrapolate(&sums, target_state)
+    }
+}
+
+/// Whether each five pot pattern leads to a plant, indexed by the pattern read as binary with
+/// `#` as 1.
+type Rules = [bool; 32];
+
+fn pattern<'a, I: IntoIterator<Item = &'a bool>>(pots: I) -> usize {
+    pots.into_iter().fold(0, |pattern, &plant| pattern << 1 | plant as usize)
+}
+
+fn automaton(rules: Rules) -> Automaton<bool, impl Fn(&bool, &[&bool]) -> bool> {
+    Automaton::new(Neighbourhood::window(2), Edges::Growing, false, move |_pot, window| {
+        rules[pattern(window.iter().cloned())]
+    })
+}
+
+/// Adds up the numbers of the pots with plants in.
+fn plant_sum(state: &Grid<bool>) -> i64 {
+    state
+        .iter()
+        .filter(|&(_point, &plant)| plant)
+        .map(|(point, _plant)| i64::from(point.x))
+        .sum()
+}
+
+fn read_initial_state_from_line(line: Line) -> Result<Grid<bool>, InputError> {
+    lazy_static! {
+        static ref initial_state_regex: Regex = Regex::new(r"^initial state: ([#.]+)$").unwrap();
+    }
+    let captures = line.captures(&initial_state_regex, "an initial state like \"initial state: #..#.#\"")?;
+    let plants: Vec<bool> = captures[1].chars().map(|plant_char| plant_char == '#').collect();
+    Ok(Grid::from_fn(Point::new(0, 0), plants.len(), 1, |point| plants[point.x as usize]))
+}
+
+fn read_rules_from_lines(lines: Lines) -> Result<Rules, ParseErrors> {
+    lazy_static! {
+        static ref rule_regex: Regex = Regex::new(r"^([#.]{5}) => ([#.])$").unwrap();
+    }
+    let rules = lines.map(|line| {
+        let captures = line.captures(&rule_regex, "a rule like \"...## => #\"")?;
+        let precedent: Vec<bool> = captures[1].chars().map(|c| c == '#').collect();
+        Ok((pattern(&precedent), &captures[2] == "#"))
+    });
+    let mut table = [false; 32];
+    for (precedent, result) in aoc_common::collect_results(rules)? {
+        table[precedent] = result;
+    }
+    Ok(table)
+}
+
+/// Draws the given range of pots, `#` for a plant and `.` for an empty pot.
+fn _format_plant_state(plants: &Grid<bool>, pots: Range<i32>) -> String {
+    pots.map(|index| plants.get(Point::new(index, 0)) == Some(&true))
+        .map(|plant_exists| match plant_exists {
+            true => String::from("#"),
+            false => String::from("."),
+        })
+        .collect::<Vec<String>>()
+        .join("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_generations() {
+        let plants = Day12::parse(include_str!("../fixtures/example.txt"), &Params::new()).unwrap();
+        let generations: Vec<String> = automaton(plants.rules)
+            .run(plants.initial_state)
+            .take(21)
+            .map(|state| _format_plant_state(&state, -3..36))
+            .collect();
+        assert_eq!(generations, include_str!("../fixtures/example-generations.txt").lines().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn rejects_negative_generations() {
+        let mut params = Params::new();
+        params.insert("generations", "-1");
+        assert!(Day12::parse(include_str!("../fixtures/example.txt"), &params).is_err());
+    }
+}
@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use aoc_common::{Grid, Params, Point, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+
+    type Input = WorldState;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str, _params: &Params) -> Result<WorldState, Box<dyn Error>> {
+        let map = Grid::parse(input, ' ', |c| if " |-+/\\^v><".contains(c) { Some(c) } else { None })?;
+        let carts: HashMap<usize, Cart> = map
+            .iter()
+            .filter_map(|(position, &c)| {
+                let direction = match c {
+                    '^' => CartDirection::Up,
+                    'v' => CartDirection::Down,
+                    '<' => CartDirection::Left,
+                    '>' => CartDirection::Right,
+                    _ => return None,
+                };
+                Some(Cart {
+                    position,
+                    direction,
+                    next_turn: NextTurn::Left,
+                })
+            })
+            .enumerate()
+            .collect();
+        if carts.is_empty() {
+            return Err("The map has no carts on it".into());
+        }
+        Ok(WorldState {
+            track_map: map.map(|_position, &c| read_track(c)),
+            carts,
+        })
+    }
+
+    fn part1(initial_state: &WorldState) -> String {
+        let mut state = initial_state.clone();
+        loop {
+            let (next_state, crashes) = next_world_state(&state);
+            if let Some(first_crash) = crashes.first() {
+                return format!("{},{}", first_crash.x, first_crash.y);
+            }
+            state = next_state;
+        }
+    }
+
+    fn part2(initial_state: &WorldState) -> String {
+        let mut state = initial_state.clone();
+        while state.carts.len() > 1 {
+            state = next_world_state(&state).0;
+        }
+        let final_cart = state.carts.values().next().expect("No carts survived");
+        format!("{},{}", final_cart.position.x, final_cart.position.y)
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Track {
+    Vertical,
+    Horizontal,
+    Intersection,
+    CornerForwardSlash,
+    CornerBackSlash,
+}
+
+#[derive(Clone, Debug)]
+enum CartDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Clone, Debug)]
+enum NextTurn {
+    Left,
+    Straight,
+    Right,
+}
+
+fn right_turn(relative_to: &CartDirection) -> CartDirection {
+    match relative_to {
+        CartDirection::Up => CartDirection::Right,
+        CartDirection::Down => CartDirection::Left,
+        CartDirection::Left => CartDirection::Up,
+        CartDirection::Right => CartDirection::Down,
+    }
+}
+
+fn left_turn(relative_to: &CartDirection) -> CartDirection {
+    match relative_to {
+        CartDirection::Up => CartDirection::Left,
+        CartDirection::Down => CartDirection::Right,
+        CartDirection::Left => CartDirection::Down,
+        CartDirection::Right => CartDirection::Up,
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Cart {
+    position: Point,
+    direction: CartDirection,
+    next_turn: NextTurn,
+}
+
+#[derive(Clone)]
+pub struct WorldState {
+    track_map: Grid<Option<Track>>,
+    carts: HashMap<usize, Cart>,
+}
+
+/// Moves every cart one tick, removing any that crash, and returns where the crashes were.
+fn next_world_state(state: &WorldState) -> (WorldState, Vec<Point>) {
+    let mut old_carts: Vec<usize> = state.carts.keys().cloned().collect();
+    old_carts.sort_by_key(|cart_id| state.carts.get(cart_id).unwrap().position);
+    old_carts.reverse();
+    let mut new_carts = HashMap::new();
+    let mut crashes = Vec::new();
+
+    while let Some(cart_id) = old_carts.pop() {
+        let mut cart = state.carts.get(&cart_id).unwrap().clone();
+        //Move cart to next position
+        let new_position = match cart.direction {
+            CartDirection::Up => cart.position.up(),
+            CartDirection::Down => cart.position.down(),
+            CartDirection::Left => cart.position.left(),
+            CartDirection::Right => cart.position.right(),
+        };
+        if old_carts.iter().any(|cart_id| state.carts.get(cart_id).unwrap().position == new_position) {
+            old_carts.retain(|cart_id| state.carts.get(cart_id).unwrap().position != new_position);
+            crashes.push(new_position);
+        } else if new_carts.values().any(|new_cart: &Cart| new_cart.position == new_position) {
+            new_carts.retain(|_cart_id, new_cart: &mut Cart| new_cart.position != new_position);
+            crashes.push(new_position);
+        } else {
+            cart.position = new_position;
+
+
+            // Turn cart if needed
+            let track = state.track_map.get(cart.position).and_then(Option::as_ref).expect("A cart ran off the track");
+            cart.direction = match track {
+                Track::Vertical => cart.direction.clone(),
+                Track::Horizontal => cart.direction.clone(),
+                Track::CornerBackSlash => match cart.direction {
+                    CartDirection::Up => CartDirection::Left,
+                    CartDirection::Down => CartDirection::Right,
+                    CartDirection::Left => CartDirection::Up,
+                    CartDirection::Right => CartDirection::Down,
+                },
+                Track::CornerForwardSlash => match cart.direction {
+                    CartDirection::Up => CartDirection::Right,
+                    CartDirection::Down => CartDirection::Left,
+                    CartDirection::Left => CartDirection::Down,
+                    CartDirection::Right => CartDirection::Up,
+                },
+                Track::Intersection => {
+                    match cart.next_turn {
+                        NextTurn::Left => {
+                            cart.next_turn = NextTurn::Straight;
+                            left_turn(&cart.direction)
+                        },
+                        NextTurn::Straight => {
+                            cart.next_turn = NextTurn::Right;
+                            cart.direction.clone()
+                        },
+                        NextTurn::Right => {
+                            cart.next_turn = NextTurn::Left;
+                            right_turn(&cart.direction)
+                        },
+                    }
+                }
+            };
+            new_carts.insert(cart_id, cart);
+        }
+    }
+    (
+        WorldState {
+            track_map: state.track_map.clone(),
+            carts: new_carts,
+        },
+        crashes,
+    )
+}
+
+fn read_track(c: char) -> Option<Track> {
+    match c {
+        '|' | '^' | 'v' => Some(Track::Vertical),
+        '-' | '<' | '>' => Some(Track::Horizontal),
+        '+' => Some(Track::Intersection),
+        '/' => Some(Track::CornerForwardSlash),
+        '\\' => Some(Track::CornerBackSlash),
+        _ => None,
+    }
+}
+
+fn _format_state(state: &WorldState) -> String {
+    let mut map = state.track_map.map(|_point, track| match track {
+        Some(Track::Horizontal) => '-',
+        Some(Track::Vertical) => '|',
+        Some(Track::Intersection) => '+',
+        Some(Track::CornerForwardSlash) => '/',
+        Some(Track::CornerBackSlash) => '\\',
+        None => ' ',
+    });
+    for cart in state.carts.values() {
+        map[cart.position] = match cart.direction {
+            CartDirection::Up => '^',
+            CartDirection::Down => 'v',
+            CartDirection::Left => '<',
+            CartDirection::Right => '>',
+        };
+    }
+    map.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_example(example: &str) -> WorldState {
+        Day13::parse(example, &Params::new()).unwrap()
+    }
+
+    #[test]
+    fn example_round_trips() {
+        let state = read_example(include_str!("../fixtures/example.txt"));
+        assert_eq!(_format_state(&state), include_str!("../fixtures/example.txt"));
+    }
+
+    #[test]
+    fn example_after_one_tick() {
+        let state = read_example(include_str!("../fixtures/example.txt"));
+        let (state, crashes) = next_world_state(&state);
+        assert!(crashes.is_empty());
+        assert_eq!(_format_state(&state), include_str!("../fixtures/example-after-1.txt"));
+    }
+
+    #[test]
+    fn example_first_crash() {
+        let mut state = read_example(include_str!("../fixtures/example.txt"));
+        for _ in 0..13 {
+            let (next_state, crashes) = next_world_state(&state);
+            assert!(crashes.is_empty());
+            state = next_state;
+        }
+        let (_state, crashes) = next_world_state(&state);
+        assert_eq!(crashes, vec![Point::new(7, 3)]);
+    }
+
+    #[test]
+    fn example_last_cart() {
+        let state = read_example(include_str!("../fixtures/example-2.txt"));
+        assert_eq!(Day13::part2(&state), "6,4");
+    }
+}
@@ -0,0 +1,129 @@
+use std::error::Error;
+
+use aoc_common::{Line, Param, Params, Solution};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    const TAKES_INPUT: bool = false;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "recipes",
+        description: "the puzzle input, a number of recipes",
+    }];
+
+    type Input = Recipes;
+    type Part1 = String;
+    type Part2 = usize;
+
+    fn parse(_input: &str, params: &Params) -> Result<Recipes, Box<dyn Error>> {
+        let digits: String = params.get("recipes")?;
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(format!("Expected recipes to be a number, found {:?}", digits).into());
+        }
+        let count = Line::new(0, &digits).parse(&digits)?;
+        Ok(Recipes { digits, count })
+    }
+
+    fn part1(recipes: &Recipes) -> String {
+        let target_recipe_number = recipes.count;
+
+        let mut state = State {
+            recipe_scores: vec![3, 7],
+            elf_recipe_indexes: vec![0, 1],
+        };
+        while state.recipe_scores.len() < (target_recipe_number + 10) {
+            state = next_state(state);
+        }
+        state.recipe_scores[target_recipe_number..target_recipe_number + 10]
+            .iter()
+            .map(usize::to_string)
+            .collect::<Vec<String>>()
+            .join("")
+    }
+
+    fn part2(recipes: &Recipes) -> usize {
+        let target_recipe_sequence: Vec<usize> = recipes
+            .digits
+            .chars()
+            .map(|c| c.to_digit(10).unwrap() as usize)
+            .collect();
+
+        let mut state = State {
+            recipe_scores: vec![3, 7],
+            elf_recipe_indexes: vec![0, 1],
+        };
+        let mut found_sequence = false;
+        let mut last_checked_index = 0;
+        while !found_sequence {
+            state = next_state(state);
+
+            // While until we have enough
+            while last_checked_index + target_recipe_sequence.len() < state.recipe_scores.len() && !found_sequence {
+                if state.recipe_scores[last_checked_index..last_checked_index + target_recipe_sequence.len()] == target_recipe_sequence[..] {
+                    found_sequence = true;
+                } else {
+                    last_checked_index += 1;
+                }
+            }
+        }
+        last_checked_index
+    }
+}
+
+/// The puzzle input, which part 1 reads as a number and part 2 as a sequence of digits.
+#[derive(Debug)]
+pub struct Recipes {
+    digits: String,
+    count: usize,
+}
+
+struct State {
+    recipe_scores: Vec<usize>,
+    elf_recipe_indexes: Vec<usize>,
+}
+
+fn next_state(old_state: State) -> State {
+    let mut selected_recipes_sum: usize = old_state.elf_recipe_indexes
+        .iter()
+        .map(|index| old_state.recipe_scores.get(*index).unwrap())
+        .sum();
+    let mut new_recipes = Vec::new();
+    while selected_recipes_sum >= 10 {
+        new_recipes.push(selected_recipes_sum % 10);
+        selected_recipes_sum /= 10;
+    }
+    new_recipes.push(selected_recipes_sum % 10);
+    new_recipes.reverse();
+    let mut all_new_recipes = old_state.recipe_scores;
+    all_new_recipes.append(&mut new_recipes);
+
+    let new_elf_recipe_indexes = old_state.elf_recipe_indexes
+        .into_iter()
+        .map(|index| (index + all_new_recipes.get(index).unwrap() + 1) % all_new_recipes.len())
+        .collect();
+
+    State {
+        recipe_scores: all_new_recipes,
+        elf_recipe_indexes: new_elf_recipe_indexes,
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(recipes: &str) -> Result<Recipes, Box<dyn Error>> {
+        let mut params = Params::new();
+        params.insert("recipes", recipes);
+        Day14::parse("", &params)
+    }
+
+    #[test]
+    fn reads_recipes_as_a_number_and_digits() {
+        let recipes = read("01245").unwrap();
+        assert_eq!(recipes.count, 1245);
+        assert_eq!(Day14::part1(&read("2018").unwrap()), "5941429882");
+        assert_eq!(Day14::part2(&recipes), 5);
+        assert!(read("99999999999999999999999").unwrap_err().to_string().contains("number too large"));
+    }
+}
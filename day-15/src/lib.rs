@@ -0,0 +1,384 @@
+use std::error::Error;
+use std::fmt;
+
+use aoc_common::{Grid, Params, Point, Search, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+
+    type Input = WorldState;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str, _params: &Params) -> Result<WorldState, Box<dyn Error>> {
+        const STARTING_HEALTH: i32 = 200;
+
+        let map = Grid::parse(input, '.', |c| if "#.EG".contains(c) { Some(c) } else { None })?;
+        let units: Vec<Unit> = map
+            .iter()
+            .filter_map(|(point, &c)| {
+                let team = match c {
+                    'E' => UnitTeam::Elf,
+                    'G' => UnitTeam::Goblin,
+                    _ => return None,
+                };
+                Some(Unit {
+                    position: point,
+                    health: STARTING_HEALTH,
+                    team,
+                })
+            })
+            .collect();
+        if units.is_empty() {
+            return Err("The map has no units on it".into());
+        }
+        Ok(WorldState {
+            cavern: map.map(|_point, &c| if c == '#' { Square::Wall } else { Square::Open }),
+            units,
+            elf_attack: 3,
+            goblin_attack: 3,
+        })
+    }
+
+    fn part1(initial_world_state: &WorldState) -> i32 {
+        combat_outcome(initial_world_state.clone())
+    }
+
+    fn part2(initial_world_state: &WorldState) -> i32 {
+        let mut lower_bound_attack = 0;
+        let mut upper_bound_attack = 200;
+
+        while (upper_bound_attack - lower_bound_attack) > 1 {
+            let attack = (upper_bound_attack - lower_bound_attack) / 2 + lower_bound_attack;
+            let mut world_state = initial_world_state.clone();
+            world_state.elf_attack = attack;
+            let mut combat_over = false;
+            let mut dead_elf = false;
+            while !combat_over {
+                combat_over = !world_state.tick();
+                dead_elf = world_state
+                    .units
+                    .iter()
+                    .filter(|unit| unit.team == UnitTeam::Elf)
+                    .any(|unit| unit.health <= 0);
+            }
+            aoc_common::diagnostic!(
+                "Attack value {}: {}",
+                attack,
+                if dead_elf { "elf died" } else { "flawless" }
+            );
+            if dead_elf {
+                lower_bound_attack = attack;
+            } else {
+                upper_bound_attack = attack;
+            }
+        }
+        let chosen_attack_value = lower_bound_attack + 1;
+        aoc_common::record("elf_attack", i64::from(chosen_attack_value));
+        let mut world_state = initial_world_state.clone();
+        world_state.elf_attack = chosen_attack_value;
+        combat_outcome(world_state)
+    }
+}
+
+/// Runs the combat to completion, returning the number of full rounds times the health left.
+fn combat_outcome(mut world_state: WorldState) -> i32 {
+    let mut ticks = 0;
+    while world_state.tick() {
+        ticks += 1;
+    }
+
+    let health_sum = world_state
+        .units
+        .iter()
+        .map(|unit| unit.health)
+        .filter(|&health| health > 0)
+        .sum::<i32>();
+    aoc_common::record("ticks", i64::from(ticks));
+    aoc_common::record("health_sum", i64::from(health_sum));
+    ticks * health_sum
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Square {
+    Wall,
+    Open,
+}
+
+impl fmt::Display for Square {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Square::Wall => "#",
+                Square::Open => ".",
+            }
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct WorldState {
+    cavern: Grid<Square>,
+    units: Vec<Unit>,
+    elf_attack: i32,
+    goblin_attack: i32,
+}
+
+impl WorldState {
+    // Returns whether the combat is over
+    fn tick(&mut self) -> bool {
+        // Create a combined list of elf and goblin refs
+        let mut sorted_units: Vec<(usize, &Unit)> = self.units.iter().enumerate().collect();
+
+        // Sort list based on position
+        sorted_units.sort_by_key(|(_unit_index, unit)| &unit.position);
+
+        // Use indexes to avoid borrow checker shenanigans
+        let sorted_units_indexes: Vec<usize> = sorted_units
+            .into_iter()
+            .map(|(unit_index, _unit)| unit_index)
+            .collect();
+        // Perform turn for each unit on list
+        for unit_index in sorted_units_indexes {
+            let unit = self.units.get(unit_index).unwrap();
+
+            //Ignore if unit is dead
+            if unit.health <= 0 {
+                continue;
+            }
+
+            //            println!("Unit {:?} at {:?}'s turn begins", unit.team, unit.position);
+            // Find targets
+            let possible_targets: Vec<&Unit> = self
+                .units
+                .iter()
+                .filter(|other_unit| unit.team != other_unit.team)
+                .filter(|unit| unit.health > 0)
+                .collect();
+            //                        println!("{:?}", possible_targets);
+
+            // Combat over if no possible targets found
+            if possible_targets.is_empty() {
+                //                                println!("Combat over");
+                return false;
+            }
+
+            let possible_attack_squares: Vec<Point> = possible_targets
+                .into_iter()
+                .flat_map(|unit| unit.position.neighbours4())
+                .collect();
+            //            println!("{:?}", possible_attack_squares);
+
+            // Filter out reachable squares, find nearest ones
+            let distances = self.search_from(unit.position, unit);
+            let (mut nearest_attack_squares, min_distance_to_an_attack_square) =
+                possible_attack_squares
+                    .into_iter()
+                    .filter_map(|square| distances.distance(&square).map(|distance| (square, distance)))
+                    .fold(
+                        (Vec::new(), u64::MAX),
+                        |(mut attack_squares, min_distance), (new_attack_square, new_distance)| {
+                            if new_distance < min_distance {
+                                attack_squares.clear();
+                                attack_squares.push(new_attack_square);
+                                (attack_squares, new_distance)
+                            } else if new_distance == min_distance {
+                                attack_squares.push(new_attack_square);
+                                (attack_squares, min_distance)
+                            } else {
+                                (attack_squares, min_distance)
+                            }
+                        },
+                    );
+            //                        println!("{:?}", nearest_attack_squares);
+
+            // Select attack square
+            nearest_attack_squares.sort();
+            let chosen_attack_square = nearest_attack_squares.first();
+            //                        println!("{:?}", chosen_attack_square);
+
+            // Select best adjacent square to move into
+            if min_distance_to_an_attack_square > 0 {
+                if let Some(attack_square) = chosen_attack_square {
+                    let distances = self.search_from(*attack_square, unit);
+                    let mut candidate_squares = vec![unit.position];
+                    candidate_squares.extend(unit.position.neighbours4());
+                    let (mut best_moves, _min_distance) = candidate_squares
+                        .into_iter()
+                        .filter_map(|candidate| distances.distance(&candidate).map(|distance| (candidate, distance)))
+                        .fold(
+                            (Vec::new(), u64::MAX),
+                            |(mut min_squares, min_distance), (candidate, distance)| {
+                                if distance < min_distance {
+                                    min_squares.clear();
+                                    min_squares.push(candidate);
+                                    (min_squares, distance)
+                                } else if distance == min_distance {
+                                    min_squares.push(candidate);
+                                    (min_squares, min_distance)
+                                } else {
+                                    (min_squares, min_distance)
+                                }
+                            },
+                        );
+                    best_moves.sort();
+                    if let Some(chosen_move) = best_moves.first() {
+                        // Shadow with a mutable reference after immutable ref no longer needed
+                        let unit = self.units.get_mut(unit_index).unwrap();
+                        unit.position = *chosen_move;
+                    }
+                }
+            }
+
+            // Remake immutable ref for attack phase
+            let unit = self.units.get(unit_index).unwrap();
+
+            // Attack if we can
+            if min_distance_to_an_attack_square <= 1 {
+                let mut possible_targets: Vec<(usize, &Unit)> = self
+                    .units
+                    .iter()
+                    .enumerate()
+                    .filter(|(_index, other_unit)| {
+                        other_unit.position.manhattan_distance(unit.position) == 1
+                    })
+                    .filter(|(_index, other_unit)| other_unit.team != unit.team)
+                    .filter(|(_index, unit)| unit.health > 0)
+                    .collect();
+                possible_targets.sort_by(|(_index, unit), (_index_other, other)| {
+                    if unit.health == other.health {
+                        unit.position.cmp(&other.position)
+                    } else {
+                        unit.health.cmp(&other.health)
+                    }
+                });
+                if let Some((index, _attack_target)) = possible_targets.first() {
+                    // Clone index and attack value as it is a reference to units atm - this allows us to mutably borrow at end of this block
+                    let index = *index;
+                    let attack_value = match unit.team {
+                        UnitTeam::Elf => self.elf_attack,
+                        UnitTeam::Goblin => self.goblin_attack,
+                    };
+                    let attack_target = self.units.get_mut(index).unwrap();
+                    attack_target.health -= attack_value;
+                }
+            }
+        }
+        true
+    }
+
+    /// Finds how far every square reachable from the point is, with the walls and every other
+    /// living unit in the way.
+    fn search_from(&self, from: Point, unit: &Unit) -> Search<Point> {
+        let mut passable = self.cavern.map(|_point, &square| square == Square::Open);
+        for other_unit in self
+            .units
+            .iter()
+            .filter(|unit| unit.health > 0)
+            .filter(|other_unit| other_unit.position != unit.position)
+        {
+            passable[other_unit.position] = false;
+        }
+        aoc_common::bfs(
+            from,
+            |point| {
+                point
+                    .neighbours4()
+                    .filter(|next| passable.get(*next) == Some(&true))
+                    .collect::<Vec<_>>()
+            },
+            |_| false,
+        )
+    }
+
+    /// Draws the walls and living units, one line per row.
+    fn format_map(&self) -> String {
+        let mut map = self.cavern.map(|_point, square| square.to_string());
+        for unit in self.units.iter().filter(|unit| unit.health > 0) {
+            map[unit.position] = String::from(match unit.team {
+                UnitTeam::Elf => "E",
+                UnitTeam::Goblin => "G",
+            });
+        }
+        map.to_string()
+    }
+}
+
+impl fmt::Display for WorldState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.format_map())?;
+        writeln!(
+            f,
+            "{:?}",
+            self.units
+                .iter()
+                .map(|unit| unit.health)
+                .collect::<Vec<i32>>()
+        )
+    }
+}
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum UnitTeam {
+    Elf,
+    Goblin,
+}
+#[derive(Clone, Debug)]
+struct Unit {
+    position: Point,
+    health: i32,
+    team: UnitTeam,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_example(example: &str) -> WorldState {
+        Day15::parse(example, &Params::new()).unwrap()
+    }
+
+    #[test]
+    fn rejects_a_map_without_units() {
+        assert!(Day15::parse("#####\n#...#\n#####\n", &Params::new()).is_err());
+        assert!(Day15::parse("", &Params::new()).is_err());
+    }
+
+    #[test]
+    fn example_movement() {
+        let mut world_state = read_example(include_str!("../fixtures/example-movement.txt"));
+        let expected_maps = [
+            include_str!("../fixtures/example-movement-after-1.txt"),
+            include_str!("../fixtures/example-movement-after-2.txt"),
+            include_str!("../fixtures/example-movement-after-3.txt"),
+        ];
+        for expected_map in expected_maps.iter() {
+            assert!(world_state.tick());
+            assert_eq!(world_state.format_map(), *expected_map);
+        }
+    }
+
+    #[test]
+    fn example_combat() {
+        let mut world_state = read_example(include_str!("../fixtures/example-combat.txt"));
+        let mut ticks = 0;
+        while world_state.tick() {
+            ticks += 1;
+        }
+        assert_eq!(ticks, 47);
+        assert_eq!(
+            world_state.format_map(),
+            include_str!("../fixtures/example-combat-end.txt")
+        );
+        let healths: Vec<i32> = world_state
+            .units
+            .iter()
+            .map(|unit| unit.health)
+            .filter(|&health| health > 0)
+            .collect();
+        assert_eq!(healths, vec![200, 131, 59, 200]);
+    }
+}
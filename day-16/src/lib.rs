@@ -0,0 +1,264 @@
+#[macro_use]
+extern crate lazy_static;
+extern crate regex;
+
+use std::collections::HashSet;
+use std::error::Error;
+
+use aoc_common::{InputError, Line, Lines, Params, Solution};
+use elfcode::{Instruction, Machine, Op, Program};
+use regex::{Captures, Regex};
+
+mod report;
+mod solver;
+pub use report::{SampleAnalysis, SampleReport};
+pub use solver::OpcodeError;
+use solver::NUM_OPCODES;
+
+pub struct Day16;
+
+const NUM_REGISTERS: usize = 4;
+
+pub struct Manual {
+    samples: Vec<Sample>,
+    program: Vec<Row>,
+}
+
+impl Manual {
+    /// Reads the samples and the program rows, without working out what the opcodes are, so
+    /// that a manual whose samples don't pin them down can still be looked into.
+    pub fn read(input: &str) -> Result<Manual, InputError> {
+        let (samples, program) = read_samples_and_program_from_lines(aoc_common::lines(input))?;
+        Ok(Manual { samples, program })
+    }
+
+    /// The manual's program, with each opcode replaced by the op that the samples show it
+    /// stands for.
+    pub fn disassemble(&self) -> Result<Program, Box<dyn Error>> {
+        let opcode_map = solver::solve(&self.samples)?;
+        Ok(disassemble(&self.program, &opcode_map)?)
+    }
+}
+
+/// A manual along with its program, disassembled, so that it is known to run.
+pub struct Decoded {
+    manual: Manual,
+    program: Program,
+}
+
+/// A row of the manual's program: an opcode and its three arguments.
+#[derive(Debug)]
+struct Row {
+    /// The zero-based index of the row's line in the input, and its text, for errors.
+    index: usize,
+    text: String,
+    codes: Vec<usize>,
+}
+
+/// Turns rows of an opcode and its three arguments into a program, whose text
+/// `elfcode::Program::parse` reads back. Fails at the first argument naming a register
+/// that the device doesn't have, once the opcode's op says which arguments are registers.
+fn disassemble(rows: &[Row], opcode_map: &[Op; NUM_OPCODES]) -> Result<Program, InputError> {
+    let instructions = rows
+        .iter()
+        .map(|row| {
+            let op = opcode_map[row.codes[0]];
+            let instruction = Instruction::new(op, row.codes[1], row.codes[2], row.codes[3]);
+            if instruction.fits_registers(NUM_REGISTERS) {
+                return Ok(instruction);
+            }
+            let line = Line::new(row.index, &row.text);
+            let fields = line.fields(" ", 4)?;
+            let registers = [op.a_is_register(), op.b_is_register(), true];
+            let field = (1..4)
+                .find(|&field| registers[field - 1] && row.codes[field] >= NUM_REGISTERS)
+                .unwrap_or(3);
+            Err(line.error_at(
+                fields[field],
+                format!(
+                    "Opcode {} is {}, which needs a register here, but there are only {}",
+                    row.codes[0], op, NUM_REGISTERS
+                ),
+            ))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Program {
+        ip_register: None,
+        register_count: NUM_REGISTERS,
+        instructions,
+    })
+}
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+
+    type Input = Decoded;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    // Work out the opcodes up front, so that samples that don't pin them down, or a program
+    // that doesn't fit the device, are reported as errors rather than failing part 2. A manual
+    // with only samples, like the example, has nothing to disassemble.
+    fn parse(input: &str, _params: &Params) -> Result<Decoded, Box<dyn Error>> {
+        let manual = Manual::read(input)?;
+        let program = if manual.program.is_empty() {
+            Program {
+                ip_register: None,
+                register_count: NUM_REGISTERS,
+                instructions: Vec::new(),
+            }
+        } else {
+            manual.disassemble()?
+        };
+        Ok(Decoded { manual, program })
+    }
+
+    fn part1(decoded: &Decoded) -> usize {
+        decoded
+            .manual
+            .samples
+            .iter()
+            .filter(|sample| possible_ops(sample).len() >= 3)
+            .count()
+    }
+
+    fn part2(decoded: &Decoded) -> usize {
+        let program = decoded.program.compile();
+        let mut machine = Machine::for_program(&program);
+        machine.run(&program);
+        machine.registers()[0]
+    }
+}
+
+/// Every op that would turn the sample's before state into its after state.
+fn possible_ops(sample: &Sample) -> HashSet<Op> {
+    Op::ALL
+        .iter()
+        .cloned()
+        .filter(|&op| {
+            let instruction = Instruction::new(op, sample.opcode[1], sample.opcode[2], sample.opcode[3]);
+            if !instruction.fits_registers(NUM_REGISTERS) {
+                return false;
+            }
+            let mut registers = sample.before_state.clone();
+            instruction.execute(&mut registers);
+            registers == sample.after_state
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+struct Sample {
+    /// The line number of the sample's before state.
+    line_number: usize,
+    before_state: Vec<usize>,
+    opcode: Vec<usize>,
+    after_state: Vec<usize>,
+}
+
+fn read_samples_and_program_from_lines(mut lines: Lines) -> Result<(Vec<Sample>, Vec<Row>), InputError> {
+    lazy_static! {
+        static ref before_regex: Regex =
+            Regex::new(r"Before: \[(\d+), (\d+), (\d+), (\d+)\]").unwrap();
+        static ref after_regex: Regex = Regex::new(r"After:  \[(\d+), (\d+), (\d+), (\d+)\]").unwrap();
+    }
+    let mut samples = Vec::new();
+    // Read samples
+    loop {
+        let before_line = match lines.next() {
+            Some(line) if !line.text.is_empty() => line,
+            _ => break,
+        };
+        let opcode_line = lines.expect_line("a sample's instruction")?;
+        let after_line = lines.expect_line("a sample's after state")?;
+        let _blank_line = lines.next();
+
+        let before_captures =
+            before_line.captures(&before_regex, "a sample's before state like \"Before: [3, 2, 1, 1]\"")?;
+        let before_state = read_registers(before_line, &before_captures)?;
+        let after_captures =
+            after_line.captures(&after_regex, "a sample's after state like \"After:  [3, 2, 2, 1]\"")?;
+        let after_state = read_registers(after_line, &after_captures)?;
+        let opcode = read_opcode_line(opcode_line)?;
+        samples.push(Sample {
+            line_number: before_line.index + 1,
+            before_state,
+            opcode,
+            after_state,
+        })
+    }
+
+    // Read remaining program opcode lines
+    let opcodes = lines
+        .filter(|line| !line.text.is_empty())
+        .map(|line| {
+            Ok(Row {
+                index: line.index,
+                text: line.text.to_owned(),
+                codes: read_opcode_line(line)?,
+            })
+        })
+        .collect::<Result<_, InputError>>()?;
+    Ok((samples, opcodes))
+}
+
+fn read_registers(line: Line, captures: &Captures) -> Result<Vec<usize>, InputError> {
+    (1..5).map(|group| line.parse::<usize>(&captures[group])).collect()
+}
+
+fn read_opcode_line(line: Line) -> Result<Vec<usize>, InputError> {
+    let fields = line.fields(" ", 4)?;
+    let codes = fields
+        .iter()
+        .map(|code| line.parse::<usize>(code))
+        .collect::<Result<Vec<_>, _>>()?;
+    if codes[0] >= NUM_OPCODES {
+        return Err(line.error_at(
+            fields[0],
+            format!("There are only {} opcodes, numbered from 0", NUM_OPCODES),
+        ));
+    }
+    Ok(codes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_sample() {
+        let manual = Day16::parse(include_str!("../fixtures/example.txt"), &Params::new()).unwrap().manual;
+        assert_eq!(manual.samples.len(), 1);
+        assert!(manual.program.is_empty());
+        let expected: HashSet<Op> = vec![Op::Mulr, Op::Addi, Op::Seti].into_iter().collect();
+        assert_eq!(possible_ops(&manual.samples[0]), expected);
+    }
+
+    fn rows(program: &str) -> Vec<Row> {
+        Manual::read(&format!("\n\n\n{}", program)).unwrap().program
+    }
+
+    #[test]
+    fn disassembles_to_text_that_parses_back() {
+        let mut opcode_map = Op::ALL;
+        opcode_map.reverse();
+        let program = disassemble(&rows("6 0 3 2\n15 1 2 3"), &opcode_map).unwrap();
+        assert_eq!(program.to_string(), "seti 0 3 2\naddr 1 2 3\n");
+        assert_eq!(Program::parse(&program.to_string(), NUM_REGISTERS).unwrap(), program);
+
+        // Opcode 6 is seti, which only writes a register
+        let error = disassemble(&rows("6 9 9 9"), &opcode_map).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 7: Opcode 6 is seti, which needs a register here, but there are only 4\n\
+             4 | 6 9 9 9\n  |       ^"
+        );
+    }
+
+    #[test]
+    fn a_program_needs_its_opcodes_pinned_down() {
+        let input = format!("{}\n\n\n0 1 2 3", include_str!("../fixtures/example.txt").trim_end());
+        let error = Day16::parse(&input, &Params::new()).err().unwrap();
+        assert!(error.to_string().starts_with("The samples fit "));
+    }
+}
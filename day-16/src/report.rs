@@ -0,0 +1,172 @@
+use std::fmt::Write;
+
+use elfcode::Op;
+
+use crate::solver::{self, op_index, OpSet, ALL_OPS, NUM_OPCODES};
+use crate::{possible_ops, Manual};
+
+/// What one sample says about its opcode, and how much is still unknown once it's known.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SampleAnalysis {
+    pub line_number: usize,
+    pub opcode: usize,
+    /// The ops that the sample behaves like.
+    pub candidates: Vec<Op>,
+    /// How many opcodes could still be more than one op, given this sample and the ones before
+    /// it, or None if those samples already contradict each other.
+    pub ambiguous_after: Option<usize>,
+}
+
+/// How every sample in the manual narrows down the opcode map.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SampleReport {
+    pub samples: Vec<SampleAnalysis>,
+    /// How many of each opcode's samples each op is consistent with, by opcode and then by
+    /// position in `Op::ALL`.
+    pub matrix: [[usize; NUM_OPCODES]; NUM_OPCODES],
+    /// How many samples there are of each opcode.
+    pub sample_counts: [usize; NUM_OPCODES],
+}
+
+impl SampleReport {
+    pub fn new(manual: &Manual) -> SampleReport {
+        let mut candidates = [ALL_OPS; NUM_OPCODES];
+        let mut matrix = [[0; NUM_OPCODES]; NUM_OPCODES];
+        let mut sample_counts = [0; NUM_OPCODES];
+        let samples = manual
+            .samples
+            .iter()
+            .map(|sample| {
+                let opcode = sample.opcode[0];
+                let ops = possible_ops(sample);
+                let mut sample_candidates: Vec<Op> = ops.iter().cloned().collect();
+                sample_candidates.sort();
+                for &op in &ops {
+                    matrix[opcode][op_index(op)] += 1;
+                }
+                sample_counts[opcode] += 1;
+                candidates[opcode] &= solver::op_set(ops);
+                let possible = solver::possibilities(&candidates);
+                let contradicted = possible.contains(&0);
+                SampleAnalysis {
+                    line_number: sample.line_number,
+                    opcode,
+                    candidates: sample_candidates,
+                    ambiguous_after: if contradicted { None } else { Some(count_ambiguous(&possible)) },
+                }
+            })
+            .collect();
+        SampleReport {
+            samples,
+            matrix,
+            sample_counts,
+        }
+    }
+
+    /// One row per sample, with its candidate ops separated by spaces.
+    pub fn samples_csv(&self) -> String {
+        let mut csv = String::from("line,opcode,candidates,candidate_count,ambiguous_after\n");
+        for sample in &self.samples {
+            writeln!(
+                csv,
+                "{},{},{},{},{}",
+                sample.line_number,
+                sample.opcode,
+                names(&sample.candidates),
+                sample.candidates.len(),
+                sample.ambiguous_after.map_or(String::new(), |count| count.to_string())
+            )
+            .unwrap();
+        }
+        csv
+    }
+
+    pub fn samples_table(&self) -> String {
+        let mut table = format!("{:>6}  {:>6}  {:>9}  candidates\n", "line", "opcode", "ambiguous");
+        for sample in &self.samples {
+            writeln!(
+                table,
+                "{:>6}  {:>6}  {:>9}  {}",
+                sample.line_number,
+                sample.opcode,
+                sample
+                    .ambiguous_after
+                    .map_or(String::from("conflict"), |count| count.to_string()),
+                names(&sample.candidates)
+            )
+            .unwrap();
+        }
+        table
+    }
+
+    /// One row per opcode, with its sample count and then a column for each op.
+    pub fn matrix_csv(&self) -> String {
+        let mut csv = String::from("opcode,samples");
+        for op in Op::ALL.iter() {
+            write!(csv, ",{}", op).unwrap();
+        }
+        csv.push('\n');
+        for (opcode, row) in self.matrix.iter().enumerate() {
+            write!(csv, "{},{}", opcode, self.sample_counts[opcode]).unwrap();
+            for count in row {
+                write!(csv, ",{}", count).unwrap();
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// The matrix, marking each op that's consistent with all of an opcode's samples.
+    pub fn matrix_table(&self) -> String {
+        let mut table = format!("{:>6}  {:>7}", "opcode", "samples");
+        for op in Op::ALL.iter() {
+            write!(table, "  {:>5}", op).unwrap();
+        }
+        table.push('\n');
+        for (opcode, row) in self.matrix.iter().enumerate() {
+            write!(table, "{:>6}  {:>7}", opcode, self.sample_counts[opcode]).unwrap();
+            for &count in row {
+                let all = count == self.sample_counts[opcode] && count > 0;
+                write!(table, "  {:>5}", format!("{}{}", count, if all { "*" } else { "" })).unwrap();
+            }
+            table.push('\n');
+        }
+        table
+    }
+}
+
+fn count_ambiguous(possible: &[OpSet; NUM_OPCODES]) -> usize {
+    possible.iter().filter(|ops| ops.count_ones() > 1).count()
+}
+
+fn names(ops: &[Op]) -> String {
+    ops.iter().map(|op| op.name()).collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_each_sample_and_the_matrix() {
+        let manual = Manual::read(include_str!("../fixtures/example.txt")).unwrap();
+        let report = SampleReport::new(&manual);
+        assert_eq!(
+            report.samples,
+            vec![SampleAnalysis {
+                line_number: 1,
+                opcode: 9,
+                candidates: vec![Op::Addi, Op::Mulr, Op::Seti],
+                ambiguous_after: Some(16),
+            }]
+        );
+        assert_eq!(report.sample_counts[9], 1);
+        assert_eq!(report.matrix[9][op_index(Op::Mulr)], 1);
+        assert_eq!(report.matrix[9][op_index(Op::Addr)], 0);
+        assert_eq!(
+            report.samples_csv(),
+            "line,opcode,candidates,candidate_count,ambiguous_after\n1,9,addi mulr seti,3,16\n"
+        );
+        assert!(report.matrix_csv().lines().nth(10).unwrap().starts_with("9,1,0,1,1,0,"));
+    }
+}
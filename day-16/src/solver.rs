@@ -0,0 +1,388 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+use elfcode::Op;
+
+use crate::{possible_ops, Sample};
+
+/// How many opcode numbers there are, one for each op.
+pub const NUM_OPCODES: usize = 16;
+
+/// A set of ops, as bits indexed by their position in `Op::ALL`.
+pub type OpSet = u16;
+
+pub const ALL_OPS: OpSet = !0;
+
+pub fn op_set<I: IntoIterator<Item = Op>>(ops: I) -> OpSet {
+    ops.into_iter().fold(0, |set, op| set | 1 << op_index(op))
+}
+
+pub fn op_index(op: Op) -> usize {
+    Op::ALL.iter().position(|&other| other == op).unwrap()
+}
+
+pub fn ops_in(set: OpSet) -> Vec<Op> {
+    Op::ALL.iter().cloned().filter(|&op| set & 1 << op_index(op) != 0).collect()
+}
+
+/// Why the samples don't pin down a single opcode map.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OpcodeError {
+    /// No opcode map fits every sample: between them, the samples on `sample_lines` leave the
+    /// `opcodes` with only the `ops`, which are too few to go round.
+    Contradiction {
+        opcodes: Vec<usize>,
+        ops: Vec<Op>,
+        sample_lines: Vec<usize>,
+    },
+    /// More than one opcode map fits every sample, with the `ambiguous` opcodes able to be any
+    /// of their ops.
+    Underdetermined {
+        count: u64,
+        ambiguous: BTreeMap<usize, Vec<Op>>,
+    },
+}
+
+/// Joins the items as "a, b and c".
+fn list<T: fmt::Display>(items: &[T], conjunction: &str) -> String {
+    let items: Vec<String> = items.iter().map(T::to_string).collect();
+    match items.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} {} {}", rest.join(", "), conjunction, last),
+        _ => items.join(""),
+    }
+}
+
+impl fmt::Display for OpcodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OpcodeError::Contradiction {
+                opcodes,
+                ops,
+                sample_lines,
+            } => {
+                let one_sample = sample_lines.len() == 1;
+                write!(
+                    f,
+                    "No opcode map fits every sample: the {} on {} {} ",
+                    if one_sample { "sample" } else { "samples" },
+                    if one_sample { "line" } else { "lines" },
+                    list(sample_lines, "and"),
+                )?;
+                let opcodes_text = format!(
+                    "{} {}",
+                    if opcodes.len() == 1 { "opcode" } else { "opcodes" },
+                    list(opcodes, "and")
+                );
+                match (ops.is_empty(), one_sample) {
+                    (true, true) => write!(f, "leaves {} without any op", opcodes_text),
+                    (true, false) => write!(f, "leave {} without any op", opcodes_text),
+                    (false, true) => write!(f, "only allows {} to be {}", opcodes_text, list(ops, "or")),
+                    (false, false) => write!(f, "only allow {} to be {}", opcodes_text, list(ops, "or")),
+                }
+            }
+            OpcodeError::Underdetermined { count, ambiguous } => {
+                let choices: Vec<String> = ambiguous
+                    .iter()
+                    .map(|(opcode, ops)| match ops.len() {
+                        n if n == Op::ALL.len() => format!("opcode {} could be any op", opcode),
+                        _ => format!("opcode {} could be {}", opcode, list(ops, "or")),
+                    })
+                    .collect();
+                write!(f, "The samples fit {} opcode maps: {}", count, choices.join(", "))
+            }
+        }
+    }
+}
+
+impl Error for OpcodeError {}
+
+/// What one sample says about its opcode.
+#[derive(Clone, Copy, Debug)]
+struct Constraint {
+    line_number: usize,
+    opcode: usize,
+    ops: OpSet,
+}
+
+impl Constraint {
+    fn of_sample(sample: &Sample) -> Constraint {
+        Constraint {
+            line_number: sample.line_number,
+            opcode: sample.opcode[0],
+            ops: op_set(possible_ops(sample)),
+        }
+    }
+}
+
+/// The ops that each opcode could be, given every sample of it. Opcodes without samples
+/// could be any op.
+fn candidates(constraints: &[Constraint]) -> [OpSet; NUM_OPCODES] {
+    let mut candidates = [ALL_OPS; NUM_OPCODES];
+    for constraint in constraints {
+        candidates[constraint.opcode] &= constraint.ops;
+    }
+    candidates
+}
+
+/// Matches as many opcodes to distinct ops as possible, returning the op matched to each
+/// opcode, by index into `Op::ALL`.
+fn maximum_matching(candidates: &[OpSet; NUM_OPCODES]) -> [Option<usize>; NUM_OPCODES] {
+    // The opcode that each op is matched to
+    let mut matched_opcodes: [Option<usize>; NUM_OPCODES] = [None; NUM_OPCODES];
+    for opcode in 0..NUM_OPCODES {
+        let mut visited = 0;
+        augment(candidates, opcode, &mut visited, &mut matched_opcodes);
+    }
+    let mut matched_ops = [None; NUM_OPCODES];
+    for (op, opcode) in matched_opcodes.iter().enumerate() {
+        if let Some(opcode) = *opcode {
+            matched_ops[opcode] = Some(op);
+        }
+    }
+    matched_ops
+}
+
+/// Looks for an augmenting path from the opcode, re-matching opcodes along it if found.
+fn augment(
+    candidates: &[OpSet; NUM_OPCODES],
+    opcode: usize,
+    visited: &mut OpSet,
+    matched_opcodes: &mut [Option<usize>; NUM_OPCODES],
+) -> bool {
+    for op in 0..NUM_OPCODES {
+        if candidates[opcode] & 1 << op == 0 || *visited & 1 << op != 0 {
+            continue;
+        }
+        *visited |= 1 << op;
+        let free = match matched_opcodes[op] {
+            None => true,
+            Some(other) => augment(candidates, other, visited, matched_opcodes),
+        };
+        if free {
+            matched_opcodes[op] = Some(opcode);
+            return true;
+        }
+    }
+    false
+}
+
+fn is_perfect(matching: &[Option<usize>; NUM_OPCODES]) -> bool {
+    matching.iter().all(Option::is_some)
+}
+
+/// Given a matching that leaves `unmatched` without an op, finds the opcodes reachable from it
+/// by alternating paths. Between them they can only be the ops reachable the same way, which
+/// are one fewer, so no opcode map can give them all an op.
+fn hall_violation(
+    candidates: &[OpSet; NUM_OPCODES],
+    matching: &[Option<usize>; NUM_OPCODES],
+    unmatched: usize,
+) -> (Vec<usize>, OpSet) {
+    let mut opcodes = vec![unmatched];
+    let mut ops = 0;
+    let mut next = 0;
+    while next < opcodes.len() {
+        let opcode = opcodes[next];
+        next += 1;
+        ops |= candidates[opcode];
+        for (other, op) in matching.iter().enumerate() {
+            if let Some(op) = *op {
+                if candidates[opcode] & 1 << op != 0 && !opcodes.contains(&other) {
+                    opcodes.push(other);
+                }
+            }
+        }
+    }
+    opcodes.sort();
+    (opcodes, ops)
+}
+
+/// The fewest samples of the opcode that leave it with only ops from `allowed`, found by
+/// dropping each sample that isn't needed in turn.
+fn minimal_samples(constraints: &[Constraint], opcode: usize, allowed: OpSet) -> Vec<Constraint> {
+    let mut needed: Vec<Constraint> = constraints
+        .iter()
+        .filter(|constraint| constraint.opcode == opcode)
+        .cloned()
+        .collect();
+    let mut index = 0;
+    while index < needed.len() {
+        let without = needed
+            .iter()
+            .enumerate()
+            .filter(|&(other, _)| other != index)
+            .fold(ALL_OPS, |set, (_, constraint)| set & constraint.ops);
+        if without & !allowed == 0 {
+            needed.remove(index);
+        } else {
+            index += 1;
+        }
+    }
+    needed
+}
+
+/// How many opcode maps fit the candidates, counting the ways of giving the first k opcodes
+/// each set of k ops.
+fn count_maps(candidates: &[OpSet; NUM_OPCODES]) -> u64 {
+    let mut ways = vec![0u64; 1 << NUM_OPCODES];
+    ways[0] = 1;
+    for used in 0..ways.len() {
+        let opcode = (used as OpSet).count_ones() as usize;
+        if ways[used] == 0 || opcode == NUM_OPCODES {
+            continue;
+        }
+        for op in 0..NUM_OPCODES {
+            if candidates[opcode] & 1 << op != 0 && used & 1 << op == 0 {
+                ways[used | 1 << op] += ways[used];
+            }
+        }
+    }
+    ways[ways.len() - 1]
+}
+
+/// The ops that each opcode could be in some opcode map that fits the candidates, which are
+/// none at all if no map fits.
+pub fn possibilities(candidates: &[OpSet; NUM_OPCODES]) -> [OpSet; NUM_OPCODES] {
+    let mut possible = [0; NUM_OPCODES];
+    // An opcode can be an op if the rest can still be matched up once it is
+    for (opcode, possible) in possible.iter_mut().enumerate() {
+        for op in (0..NUM_OPCODES).filter(|&op| candidates[opcode] & 1 << op != 0) {
+            let mut forced = *candidates;
+            for other in forced.iter_mut() {
+                *other &= !(1 << op);
+            }
+            forced[opcode] = 1 << op;
+            if is_perfect(&maximum_matching(&forced)) {
+                *possible |= 1 << op;
+            }
+        }
+    }
+    possible
+}
+
+/// Works out which op each opcode stands for, so that every sample behaves like its opcode's
+/// op. Fails if no opcode map fits, or if more than one does.
+pub fn solve(samples: &[Sample]) -> Result<[Op; NUM_OPCODES], OpcodeError> {
+    let constraints: Vec<Constraint> = samples.iter().map(Constraint::of_sample).collect();
+    solve_constraints(&constraints)
+}
+
+fn solve_constraints(constraints: &[Constraint]) -> Result<[Op; NUM_OPCODES], OpcodeError> {
+    let candidates = candidates(constraints);
+    let matching = maximum_matching(&candidates);
+    if let Some(unmatched) = matching.iter().position(Option::is_none) {
+        let (opcodes, ops) = hall_violation(&candidates, &matching, unmatched);
+        let mut sample_lines: Vec<usize> = opcodes
+            .iter()
+            .flat_map(|&opcode| minimal_samples(constraints, opcode, ops))
+            .map(|constraint| constraint.line_number)
+            .collect();
+        sample_lines.sort();
+        return Err(OpcodeError::Contradiction {
+            opcodes,
+            ops: ops_in(ops),
+            sample_lines,
+        });
+    }
+
+    let ambiguous: BTreeMap<usize, Vec<Op>> = possibilities(&candidates)
+        .iter()
+        .enumerate()
+        .filter(|(_, possible)| possible.count_ones() > 1)
+        .map(|(opcode, &possible)| (opcode, ops_in(possible)))
+        .collect();
+    if !ambiguous.is_empty() {
+        return Err(OpcodeError::Underdetermined {
+            count: count_maps(&candidates),
+            ambiguous,
+        });
+    }
+
+    let mut map = [Op::Addr; NUM_OPCODES];
+    for (opcode, op) in matching.iter().enumerate() {
+        map[opcode] = Op::ALL[op.unwrap()];
+    }
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constraint(line_number: usize, opcode: usize, ops: &[Op]) -> Constraint {
+        Constraint {
+            line_number,
+            opcode,
+            ops: op_set(ops.iter().cloned()),
+        }
+    }
+
+    /// Opcode n + 3 is the nth op, which a pair of samples narrows down for each.
+    fn pinned_down() -> Vec<Constraint> {
+        let mut constraints = Vec::new();
+        for (index, &op) in Op::ALL.iter().enumerate() {
+            let other = Op::ALL[(index + 1) % NUM_OPCODES];
+            let opcode = (index + 3) % NUM_OPCODES;
+            constraints.push(constraint(index * 8 + 1, opcode, &[op, other]));
+            constraints.push(constraint(index * 8 + 5, opcode, &[op]));
+        }
+        constraints
+    }
+
+    #[test]
+    fn solves_samples_that_pin_every_opcode_down() {
+        let map = solve_constraints(&pinned_down()).unwrap();
+        for (index, &op) in Op::ALL.iter().enumerate() {
+            assert_eq!(map[(index + 3) % NUM_OPCODES], op);
+        }
+    }
+
+    #[test]
+    fn counts_the_maps_that_fit_when_samples_are_missing() {
+        let mut constraints = pinned_down();
+        // Opcodes 3 and 4 are then only known to be addr or addi between them
+        constraints.retain(|constraint| constraint.opcode != 3 && constraint.opcode != 4);
+        constraints.push(constraint(200, 3, &[Op::Addr, Op::Addi]));
+        assert_eq!(
+            solve_constraints(&constraints).unwrap_err(),
+            OpcodeError::Underdetermined {
+                count: 2,
+                ambiguous: vec![(3, vec![Op::Addr, Op::Addi]), (4, vec![Op::Addr, Op::Addi])]
+                    .into_iter()
+                    .collect(),
+            }
+        );
+    }
+
+    #[test]
+    fn points_at_the_samples_that_contradict() {
+        let mut constraints = pinned_down();
+        // Leaves opcodes 3 and 5 both only able to be addr
+        constraints.retain(|constraint| constraint.opcode != 5);
+        constraints.push(constraint(200, 5, &[Op::Addr]));
+        let err = solve_constraints(&constraints).unwrap_err();
+        assert_eq!(
+            err,
+            OpcodeError::Contradiction {
+                opcodes: vec![3, 5],
+                ops: vec![Op::Addr],
+                sample_lines: vec![5, 200],
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "No opcode map fits every sample: the samples on lines 5 and 200 only allow opcodes 3 and 5 to be addr"
+        );
+    }
+
+    #[test]
+    fn points_at_a_sample_that_fits_no_op() {
+        let mut constraints = pinned_down();
+        constraints.push(constraint(200, 7, &[]));
+        assert_eq!(
+            solve_constraints(&constraints).unwrap_err().to_string(),
+            "No opcode map fits every sample: the sample on line 200 leaves opcode 7 without any op"
+        );
+    }
+}
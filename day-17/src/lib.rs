@@ -0,0 +1,269 @@
+#[macro_use]
+extern crate lazy_static;
+extern crate regex;
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use aoc_common::{Grid, InputError, Line, Params, Point, Solution};
+use regex::Regex;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+
+    type Input = Grid<Square>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str, _params: &Params) -> Result<Grid<Square>, Box<dyn Error>> {
+        let clay: Vec<Point> = aoc_common::collect_results(aoc_common::lines(input).map(read_clay_from_line))?
+            .into_iter()
+            .flatten()
+            .collect();
+        if clay.is_empty() {
+            return Err("The scan has no clay in it".into());
+        }
+        let min_x = clay.iter().map(|point| point.x).min().unwrap().min(SPRING.x);
+        let max_x = clay.iter().map(|point| point.x).max().unwrap().max(SPRING.x);
+        let max_y = clay.iter().map(|point| point.y).max().unwrap();
+        // From the spring down to the lowest clay, with a column either side for water to
+        // fall down past the outermost clay, and wide enough to hold the spring even if the
+        // clay is all off to one side of it
+        let mut world_map = Grid::new(
+            Point::new(min_x - 1, 0),
+            (max_x - min_x + 3) as usize,
+            (max_y + 1) as usize,
+            Square::Sand,
+        );
+        for point in clay {
+            world_map[point] = Square::Clay;
+        }
+        Ok(world_map)
+    }
+
+    fn part1(world_map: &Grid<Square>) -> usize {
+        part_1(&simulate_water(SPRING, world_map))
+    }
+
+    fn part2(world_map: &Grid<Square>) -> usize {
+        part_2(&simulate_water(SPRING, world_map))
+    }
+}
+
+const SPRING: Point = Point::new(500, 0);
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Square {
+    Sand,
+    Clay,
+    RunningWater,
+    PoolingWater,
+}
+
+impl fmt::Display for Square {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Square::Sand => ".",
+                Square::Clay => "#",
+                Square::RunningWater => "|",
+                Square::PoolingWater => "~",
+            }
+        )
+    }
+}
+
+fn read_clay_from_line(line: Line) -> Result<Vec<Point>, InputError> {
+    lazy_static! {
+        static ref restriction_regex: Regex = Regex::new(r"^([xy])=(\d+\.{0,2}\d*)$").unwrap();
+        static ref range_regex: Regex = Regex::new(r"(\d+)\.\.(\d+)").unwrap();
+    }
+    let mut clay = Vec::new();
+    let restrictions: HashMap<String, std::ops::Range<i32>> = line
+        .text
+        .split(", ")
+        .map(|restriction_string| {
+            let captures = restriction_regex.captures(restriction_string).ok_or_else(|| {
+                line.error_at(restriction_string, "Expected a restriction like \"x=495\" or \"y=2..7\"")
+            })?;
+            let axis = captures[1].to_owned();
+            let restriction_string = &captures[2];
+            let clay_range = match range_regex.captures(restriction_string) {
+                Some(captures) => {
+                    line.parse::<i32>(&captures[1])?..(line.parse::<i32>(&captures[2])? + 1)
+                }
+                None => {
+                    let num = line.parse::<i32>(restriction_string)?;
+                    num..(num + 1)
+                }
+            };
+            Ok((axis, clay_range))
+        })
+        .collect::<Result<_, InputError>>()?;
+    let (x_range, y_range) = match (restrictions.get("x"), restrictions.get("y")) {
+        (Some(x_range), Some(y_range)) => (x_range.clone(), y_range.clone()),
+        _ => return Err(line.error("Expected both an x and a y restriction")),
+    };
+    for x in x_range {
+        for y in y_range.clone() {
+            clay.push(Point::new(x, y));
+        }
+    }
+    Ok(clay)
+}
+
+fn simulate_water(starting_point: Point, initial_state: &Grid<Square>) -> Grid<Square> {
+    let mut world_state = initial_state.clone();
+    let mut falling_water = vec![starting_point];
+
+    while let Some(next_waterfall) = falling_water.pop() {
+        
+        let mut waterfall_bottom = next_waterfall;
+
+        // Create waterfall until we hit a non-empty square
+        while world_state.get(waterfall_bottom.down()) == Some(&Square::Sand) {
+            world_state[waterfall_bottom.down()] = Square::RunningWater;
+            waterfall_bottom = waterfall_bottom.down();
+        }
+
+        //Found a non-empty square
+        match world_state.get(waterfall_bottom.down()) {
+            Some(Square::RunningWater) => {
+                // Already calculated this square
+                
+            },
+            Some(Square::PoolingWater) |
+            Some(Square::Clay) => {
+                let mut water_rising = true;
+                while water_rising {
+                    let (rising, mut new_waterfalls) = scan_for_new_waterfalls(waterfall_bottom, &mut world_state);
+                    falling_water.append(&mut new_waterfalls);
+                    if rising {
+                        waterfall_bottom = waterfall_bottom.up();
+                    } else {
+                        water_rising = false;
+                    }
+                }
+
+            },
+            // This can only happen if we have fallen off the bottom of the map
+            Some(Square::Sand) | None => (),
+        }
+
+    }
+    world_state
+}
+
+// Returns: whether the water continues to rise and any new waterfalls
+fn scan_for_new_waterfalls(current_waterfall_bottom: Point, world_state: &mut Grid<Square>) -> (bool, Vec<Point>) {
+    let mut new_waterfalls = Vec::new();
+    let mut left = current_waterfall_bottom;
+    let mut right = current_waterfall_bottom;
+    let mut blocked_left = false;
+    let mut blocked_right = false;
+    loop {
+        match world_state[left.down()] {
+            // If there's nothing below it, then we need to make a new waterfall here
+            Square::Sand => {
+                new_waterfalls.push(left);
+                break;
+            },
+            // If there's already running water, it's already been calculated
+            Square::RunningWater => break,
+            // If there's pooling water or clay under it, the water is "supported"
+            Square::Clay |
+            Square::PoolingWater => (),
+        }
+        if world_state[left.left()] == Square::Clay {
+            blocked_left = true;
+            break;
+        }
+        left = left.left();
+    }
+
+    loop {
+        match world_state[right.down()] {
+            // If there's nothing below it, then we need to make a new waterfall here
+            Square::Sand => {
+                new_waterfalls.push(right);
+                break;
+            },
+            // If there's already running water, it's already been calculated
+            Square::RunningWater => break,
+            // If there's pooling water or clay under it, the water is "supported"
+            Square::Clay |
+            Square::PoolingWater => (),
+        }
+        if world_state[right.right()] == Square::Clay {
+            blocked_right = true;
+            break;
+        }
+        right = right.right();
+    }
+
+    let water = if blocked_left && blocked_right {
+        // Blocked on both sides -> raise water level and try again
+        Square::PoolingWater
+    } else {
+        // We were able to fall -> add any new waterfalls
+        Square::RunningWater
+    };
+    for x in left.x..right.x+1 {
+        world_state[Point::new(x, current_waterfall_bottom.y)] = water.clone();
+    }
+    (blocked_left && blocked_right, new_waterfalls)
+}
+
+// Counts the squares matching the filter, from the highest clay down
+fn count_squares<F: Fn(&Square) -> bool>(state: &Grid<Square>, filter: F) -> usize {
+    let min_y = state.iter()
+        .filter(|(_point, square)| **square == Square::Clay)
+        .map(|(point, _square)| point.y)
+        .next()
+        .unwrap_or(0);
+    state.iter()
+        .filter(|(point, square)| point.y >= min_y && filter(square))
+        .count()
+}
+
+fn part_1(state: &Grid<Square>) -> usize {
+    count_squares(state, |square| *square == Square::RunningWater || *square == Square::PoolingWater)
+}
+
+fn part_2(state: &Grid<Square>) -> usize {
+    count_squares(state, |square| *square == Square::PoolingWater)
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_example() -> Grid<Square> {
+        Day17::parse(include_str!("../fixtures/example.txt"), &Params::new()).unwrap()
+    }
+
+    #[test]
+    fn example_clay() {
+        assert_eq!(read_example().to_string(), include_str!("../fixtures/example-clay.txt"));
+    }
+
+    #[test]
+    fn example_water() {
+        let world_map = simulate_water(SPRING, &read_example());
+        assert_eq!(world_map.to_string(), include_str!("../fixtures/example-water.txt"));
+        assert_eq!(part_1(&world_map), 57);
+        assert_eq!(part_2(&world_map), 29);
+    }
+
+    #[test]
+    fn clay_off_to_one_side_of_the_spring() {
+        let world_map = Day17::parse("x=510, y=2..4", &Params::new()).unwrap();
+        assert!(world_map.contains(SPRING));
+        assert_eq!(Day17::part1(&world_map), 3);
+        assert_eq!(Day17::part2(&world_map), 0);
+    }
+}
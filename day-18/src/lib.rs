@@ -0,0 +1,254 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+
+use aoc_common::{Automaton, Grid, Neighbourhood, Param, Params, Solution};
+
+mod rules;
+pub use rules::Rules;
+
+pub struct Day18;
+
+pub struct Landscape {
+    initial_state: Grid<GroundState>,
+    minutes: Option<u64>,
+    rules: Rules,
+}
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "minutes",
+            description: "the target minutes (default 10 for part 1, 1000000000 for part 2)",
+        },
+        Param {
+            name: "rules",
+            description: "a file of rules to use instead of the puzzle's, like day-18/fixtures/puzzle-rules.txt",
+        },
+        Param {
+            name: "open_to_trees",
+            description: "the trees that open ground needs around it to fill with trees (default 3)",
+        },
+        Param {
+            name: "trees_to_lumberyard",
+            description: "the lumberyards that trees need around them to become one (default 3)",
+        },
+        Param {
+            name: "lumberyard_needs_lumberyards",
+            description: "the lumberyards a lumberyard needs around it to stay one (default 1)",
+        },
+        Param {
+            name: "lumberyard_needs_trees",
+            description: "the trees a lumberyard needs around it to stay one (default 1)",
+        },
+        Param {
+            name: "radius",
+            description: "how far away an acre's neighbours are (default 1)",
+        },
+        Param {
+            name: "edges",
+            description: "fixed, or wrap to wrap the area round at its edges (default fixed)",
+        },
+    ];
+
+    type Input = Landscape;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str, params: &Params) -> Result<Landscape, Box<dyn Error>> {
+        let minutes = params.get_opt("minutes")?;
+        let mut rules = match params.get_opt::<String>("rules")? {
+            // Read straight from the file, as standard input may be the puzzle input
+            Some(file) => {
+                let contents =
+                    fs::read_to_string(&file).map_err(|err| format!("Unable to read file {}: {}", file, err))?;
+                Rules::read(&contents).map_err(|err| err.in_file(&file))?
+            }
+            None => Rules::default(),
+        };
+        rules.override_with(params)?;
+        aoc_common::diagnostic!("Using {:?}", rules);
+        let initial_state = Grid::parse(input, GroundState::OpenGround, |character| match character {
+            '.' => Some(GroundState::OpenGround),
+            '|' => Some(GroundState::Trees),
+            '#' => Some(GroundState::LumberYard),
+            _ => None,
+        })?;
+        rules.check_radius(initial_state.width(), initial_state.height())?;
+        Ok(Landscape {
+            initial_state,
+            minutes,
+            rules,
+        })
+    }
+
+    fn part1(landscape: &Landscape) -> usize {
+        let state = state_after(&landscape.rules, &landscape.initial_state, landscape.minutes.unwrap_or(10) as usize);
+        resource_value(&state)
+    }
+
+    fn part2(landscape: &Landscape) -> usize {
+        let target_minutes = landscape.minutes.unwrap_or(1_000_000_000);
+        let automaton = lumber_collection(&landscape.rules);
+        let cycle = aoc_common::brent(landscape.initial_state.clone(), |state| automaton.step(state));
+        aoc_common::diagnostic!("The landscape repeats every {} minutes from minute {}", cycle.period, cycle.start);
+        aoc_common::record("cycle_start", cycle.start as i64);
+        let state = state_after(
+            &landscape.rules,
+            &landscape.initial_state,
+            cycle.first_equivalent(target_minutes),
+        );
+        resource_value(&state)
+    }
+}
+
+/// Multiplies the trees by the lumberyards, recording both so that different rules can be
+/// compared.
+fn resource_value(state: &Grid<GroundState>) -> usize {
+    let (trees_count, lumberyard_count) = count_resources(state);
+    aoc_common::record("trees", trees_count as i64);
+    aoc_common::record("lumberyards", lumberyard_count as i64);
+    lumberyard_count * trees_count
+}
+
+// Returns: the number of trees and the number of lumberyards
+fn count_resources(state: &Grid<GroundState>) -> (usize, usize) {
+    let lumberyard_count = state
+        .cells()
+        .filter(|ground_state| **ground_state == GroundState::LumberYard)
+        .count();
+
+    let trees_count = state
+        .cells()
+        .filter(|ground_state| **ground_state == GroundState::Trees)
+        .count();
+    (trees_count, lumberyard_count)
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+enum GroundState {
+    OpenGround,
+    Trees,
+    LumberYard,
+}
+
+impl fmt::Display for GroundState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                GroundState::OpenGround => ".",
+                GroundState::Trees => "|",
+                GroundState::LumberYard => "#",
+            }
+        )
+    }
+}
+
+fn lumber_collection(rules: &Rules) -> Automaton<GroundState, impl Fn(&GroundState, &[&GroundState]) -> GroundState> {
+    let rules = *rules;
+    let radius = i32::try_from(rules.radius).expect("The radius should have been checked against the area");
+    Automaton::new(
+        Neighbourhood::moore(radius),
+        rules.edges,
+        GroundState::OpenGround,
+        move |ground_state, adjacent| rules.apply(ground_state, adjacent),
+    )
+}
+
+fn state_after(rules: &Rules, initial_state: &Grid<GroundState>, minutes: usize) -> Grid<GroundState> {
+    let automaton = lumber_collection(rules);
+    let mut generations = automaton.run(initial_state.clone());
+    for _ in 0..minutes {
+        generations.advance();
+    }
+    generations.cells().clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Edges;
+
+    fn read_example() -> Grid<GroundState> {
+        Day18::parse(include_str!("../fixtures/example.txt"), &Params::new())
+            .unwrap()
+            .initial_state
+    }
+
+    #[test]
+    fn example_round_trips() {
+        assert_eq!(read_example().to_string(), include_str!("../fixtures/example.txt"));
+    }
+
+    #[test]
+    fn example_after_one_minute() {
+        let state = lumber_collection(&Rules::default()).step(&read_example());
+        assert_eq!(state.to_string(), include_str!("../fixtures/example-after-1.txt"));
+    }
+
+    #[test]
+    fn example_after_ten_minutes() {
+        let state = state_after(&Rules::default(), &read_example(), 10);
+        assert_eq!(state.to_string(), include_str!("../fixtures/example-after-10.txt"));
+        assert_eq!(count_resources(&state), (37, 31));
+    }
+
+    #[test]
+    fn reads_rules_from_a_file_and_params() {
+        assert_eq!(Rules::read(include_str!("../fixtures/puzzle-rules.txt")).unwrap(), Rules::default());
+
+        let mut params = Params::new();
+        params.insert("radius", "2");
+        params.insert("edges", "wrap");
+        let mut rules = Rules::read("open_to_trees = 4").unwrap();
+        rules.override_with(&params).unwrap();
+        assert_eq!((rules.open_to_trees, rules.radius, rules.edges), (4, 2, Edges::Wrapping));
+
+        let errors = Rules::read("radius = 1\nsize = 3\nedges = round").unwrap_err();
+        let lines: Vec<usize> = errors.0.iter().map(|error| error.line_number()).collect();
+        assert_eq!(lines, vec![2, 3]);
+        params.insert("radius", "-1");
+        assert!(rules.override_with(&params).is_err());
+    }
+
+    #[test]
+    fn rejects_negative_minutes() {
+        let mut params = Params::new();
+        params.insert("minutes", "-1");
+        assert!(Day18::parse(include_str!("../fixtures/example.txt"), &params).is_err());
+    }
+
+    #[test]
+    fn rejects_a_radius_outside_the_area() {
+        let parse_with_radius = |radius| {
+            let mut params = Params::new();
+            params.insert("radius", radius);
+            Day18::parse(include_str!("../fixtures/example.txt"), &params).map(|landscape| landscape.rules.radius)
+        };
+        assert_eq!(parse_with_radius("10").unwrap(), 10);
+        assert_eq!(
+            parse_with_radius("0").err().unwrap().to_string(),
+            "Unable to parse parameter radius from \"0\": The radius must be at least 1"
+        );
+        assert_eq!(
+            parse_with_radius("4294967295").err().unwrap().to_string(),
+            "A radius of 4294967295 reaches further than the 10 by 10 area"
+        );
+    }
+
+    #[test]
+    fn rules_that_change_nothing_leave_the_example_alone() {
+        let rules = Rules {
+            open_to_trees: 9,
+            trees_to_lumberyard: 9,
+            lumberyard_needs_lumberyards: 0,
+            lumberyard_needs_trees: 0,
+            ..Rules::default()
+        };
+        assert_eq!(state_after(&rules, &read_example(), 10), read_example());
+    }
+}
@@ -0,0 +1,146 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use aoc_common::{Edges, ParamError, Params, ParseErrors};
+
+use crate::GroundState;
+
+/// The names of the rules, as used in rule files and as parameters.
+pub const RULE_NAMES: [&str; 6] = [
+    "open_to_trees",
+    "trees_to_lumberyard",
+    "lumberyard_needs_lumberyards",
+    "lumberyard_needs_trees",
+    "radius",
+    "edges",
+];
+
+/// How the lumber collection area changes each minute. The default is the puzzle's rules.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Rules {
+    /// Open ground fills with trees if at least this many of its neighbours are trees.
+    pub open_to_trees: usize,
+    /// Trees become a lumberyard if at least this many of their neighbours are lumberyards.
+    pub trees_to_lumberyard: usize,
+    /// A lumberyard stays a lumberyard only if at least this many of its neighbours are
+    /// lumberyards, and at least `lumberyard_needs_trees` are trees.
+    pub lumberyard_needs_lumberyards: usize,
+    pub lumberyard_needs_trees: usize,
+    /// How far away, across, down or diagonally, an acre's neighbours are.
+    pub radius: u32,
+    /// Whether the area ends at its edges, or wraps round.
+    pub edges: Edges,
+}
+
+impl Default for Rules {
+    fn default() -> Rules {
+        Rules {
+            open_to_trees: 3,
+            trees_to_lumberyard: 3,
+            lumberyard_needs_lumberyards: 1,
+            lumberyard_needs_trees: 1,
+            radius: 1,
+            edges: Edges::Fixed,
+        }
+    }
+}
+
+impl Rules {
+    /// Reads `name = value` lines over the top of the puzzle's rules, skipping blank lines and
+    /// lines starting with `#`.
+    pub fn read(input: &str) -> Result<Rules, ParseErrors> {
+        let mut rules = Rules::default();
+        let results = aoc_common::lines(input)
+            .filter(|line| {
+                let text = line.text.trim();
+                !text.is_empty() && !text.starts_with('#')
+            })
+            .map(|line| {
+                let (name, value) = line
+                    .text
+                    .split_once('=')
+                    .ok_or_else(|| line.error("Expected a rule like \"radius = 1\""))?;
+                let (name, value) = (name.trim(), value.trim());
+                if !RULE_NAMES.contains(&name) {
+                    return Err(line.error_at(name, format!("Unknown rule, expected one of {}", RULE_NAMES.join(", "))));
+                }
+                rules.set(name, value).map_err(|message| line.error_at(value, message))
+            });
+        aoc_common::collect_results(results)?;
+        Ok(rules)
+    }
+
+    /// Replaces any of the rules given as parameters.
+    pub fn override_with(&mut self, params: &Params) -> Result<(), ParamError> {
+        for &name in RULE_NAMES.iter() {
+            if let Some(value) = params.get_opt::<String>(name)? {
+                self.set(name, &value).map_err(|cause| ParamError::Invalid {
+                    name: name.to_owned(),
+                    value,
+                    cause,
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "open_to_trees" => self.open_to_trees = number(value)?,
+            "trees_to_lumberyard" => self.trees_to_lumberyard = number(value)?,
+            "lumberyard_needs_lumberyards" => self.lumberyard_needs_lumberyards = number(value)?,
+            "lumberyard_needs_trees" => self.lumberyard_needs_trees = number(value)?,
+            "radius" => {
+                self.radius = match number(value)? {
+                    0 => return Err(String::from("The radius must be at least 1")),
+                    radius => radius,
+                }
+            }
+            "edges" => {
+                self.edges = match value {
+                    "fixed" => Edges::Fixed,
+                    "wrap" => Edges::Wrapping,
+                    _ => return Err(String::from("Expected \"fixed\" or \"wrap\"")),
+                }
+            }
+            _ => return Err(format!("Unknown rule {:?}", name)),
+        }
+        Ok(())
+    }
+
+    /// Checks that the neighbourhood reaches no further than the whole area, as any further
+    /// would only wrap round onto the same acres again, or see nothing but open ground.
+    pub fn check_radius(&self, width: usize, height: usize) -> Result<(), String> {
+        if u64::from(self.radius) > width.max(height) as u64 {
+            return Err(format!(
+                "A radius of {} reaches further than the {} by {} area",
+                self.radius, width, height
+            ));
+        }
+        Ok(())
+    }
+
+    /// What an acre becomes, given its neighbours.
+    pub(crate) fn apply(&self, ground_state: &GroundState, adjacent: &[&GroundState]) -> GroundState {
+        let count = |wanted: GroundState| adjacent.iter().filter(|&&other_ground_state| *other_ground_state == wanted).count();
+        match *ground_state {
+            GroundState::OpenGround if count(GroundState::Trees) >= self.open_to_trees => GroundState::Trees,
+            GroundState::Trees if count(GroundState::LumberYard) >= self.trees_to_lumberyard => GroundState::LumberYard,
+            GroundState::LumberYard
+                if count(GroundState::LumberYard) < self.lumberyard_needs_lumberyards
+                    || count(GroundState::Trees) < self.lumberyard_needs_trees =>
+            {
+                GroundState::OpenGround
+            }
+            _ => ground_state.clone(),
+        }
+    }
+}
+
+fn number<T>(value: &str) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value.parse().map_err(|err: T::Err| err.to_string())
+}
@@ -0,0 +1,233 @@
+use std::error::Error;
+
+use aoc_common::{Params, Solution};
+use elfcode::{Machine, Op, Program};
+
+pub struct Day19;
+
+const NUM_REGISTERS: usize = 6;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+
+    type Input = Program;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str, _params: &Params) -> Result<Program, Box<dyn Error>> {
+        Ok(Program::parse(input, NUM_REGISTERS)?)
+    }
+
+    fn part1(program: &Program) -> usize {
+        let program = program.compile();
+        let mut machine = Machine::for_program(&program);
+        machine.run(&program);
+        machine.registers()[0]
+    }
+
+    // With register 0 set to 1, the program sums the divisors of a much larger number (see
+    // input/notes.txt), so the loop that does so has to be skipped over
+    fn part2(program: &Program) -> usize {
+        run_summing_divisors(program, 1)
+    }
+}
+
+/// Runs the program from the given value in register 0 until it halts, returning register 0.
+/// Whenever it reaches the divisor summing loop, the loop's result is worked out directly.
+fn run_summing_divisors(program: &Program, register_0: usize) -> usize {
+    let compiled = program.compile();
+    let mut machine = Machine::for_program(&compiled);
+    machine.registers_mut()[0] = register_0;
+    match DivisorSum::find(program) {
+        Some(kernel) => {
+            while machine.run_until(&compiled, |ip, _| ip == kernel.start) {
+                let target = machine.registers()[kernel.registers[N]];
+                aoc_common::diagnostic!("Summing the divisors of {} at {}", target, kernel.start);
+                aoc_common::record("target", target as i64);
+                kernel.skip(machine.registers_mut());
+                machine.ip = kernel.end();
+            }
+        }
+        None => {
+            aoc_common::diagnostic!("Found no divisor summing loop, so running the program as it is");
+            machine.run(&compiled);
+        }
+    }
+    machine.registers()[0]
+}
+
+// The registers that the divisor summing loop uses, as indexes into `DivisorSum::registers`
+const I: usize = 0;
+const J: usize = 1;
+const TEST: usize = 2;
+const N: usize = 3;
+const SUM: usize = 4;
+
+/// An argument of an instruction in the divisor summing loop.
+#[derive(Clone, Copy, Debug)]
+enum Arg {
+    /// One of the loop's registers, which must be the same wherever it appears.
+    Register(usize),
+    /// The register bound to the instruction pointer.
+    Ip,
+    Value(usize),
+    /// A jump to the instruction this far into the loop, by setting the instruction pointer.
+    Jump(usize),
+    /// An argument that the op doesn't use.
+    Ignored,
+}
+
+/// `for i in 1..=n { for j in 1..=n { if i * j == n { sum += i } } }`, with either order of
+/// arguments allowed wherever the op doesn't care.
+const DIVISOR_SUM: [(Op, Arg, Arg, Arg); 15] = [
+    (Op::Seti, Arg::Value(1), Arg::Ignored, Arg::Register(I)),
+    (Op::Seti, Arg::Value(1), Arg::Ignored, Arg::Register(J)),
+    (Op::Mulr, Arg::Register(I), Arg::Register(J), Arg::Register(TEST)),
+    (Op::Eqrr, Arg::Register(TEST), Arg::Register(N), Arg::Register(TEST)),
+    (Op::Addr, Arg::Register(TEST), Arg::Ip, Arg::Ip),
+    (Op::Addi, Arg::Ip, Arg::Value(1), Arg::Ip),
+    (Op::Addr, Arg::Register(I), Arg::Register(SUM), Arg::Register(SUM)),
+    (Op::Addi, Arg::Register(J), Arg::Value(1), Arg::Register(J)),
+    (Op::Gtrr, Arg::Register(J), Arg::Register(N), Arg::Register(TEST)),
+    (Op::Addr, Arg::Ip, Arg::Register(TEST), Arg::Ip),
+    (Op::Seti, Arg::Jump(2), Arg::Ignored, Arg::Ip),
+    (Op::Addi, Arg::Register(I), Arg::Value(1), Arg::Register(I)),
+    (Op::Gtrr, Arg::Register(I), Arg::Register(N), Arg::Register(TEST)),
+    (Op::Addr, Arg::Register(TEST), Arg::Ip, Arg::Ip),
+    (Op::Seti, Arg::Jump(1), Arg::Ignored, Arg::Ip),
+];
+
+/// Where the program sums the divisors of a number, and which registers it uses to do so.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct DivisorSum {
+    start: usize,
+    registers: [usize; 5],
+}
+
+/// Which machine register each of the loop's registers has been matched to so far.
+type Bindings = [Option<usize>; 5];
+
+impl DivisorSum {
+    fn find(program: &Program) -> Option<DivisorSum> {
+        let ip_register = program.ip_register?;
+        let instructions = &program.instructions;
+        (0..(instructions.len() + 1).saturating_sub(DIVISOR_SUM.len())).find_map(|start| {
+            let matcher = Matcher { start, ip_register };
+            let mut bindings = [None; 5];
+            for (offset, &(op, a, b, c)) in DIVISOR_SUM.iter().enumerate() {
+                let instruction = instructions[start + offset];
+                let fits = |bindings: &mut Bindings, a_value, b_value| {
+                    matcher.bind(a, a_value, bindings)
+                        && matcher.bind(b, b_value, bindings)
+                        && matcher.bind(c, instruction.c, bindings)
+                };
+                if instruction.op != op {
+                    return None;
+                }
+                let mut attempt = bindings;
+                if fits(&mut attempt, instruction.a, instruction.b) {
+                    bindings = attempt;
+                    continue;
+                }
+                let commutative = matches!(op, Op::Addr | Op::Mulr | Op::Eqrr);
+                attempt = bindings;
+                if commutative && fits(&mut attempt, instruction.b, instruction.a) {
+                    bindings = attempt;
+                    continue;
+                }
+                return None;
+            }
+            let mut registers = [0; 5];
+            for (register, binding) in registers.iter_mut().zip(&bindings) {
+                *register = binding.expect("every register appears in the loop");
+            }
+            Some(DivisorSum { start, registers })
+        })
+    }
+
+    /// The address just after the loop.
+    fn end(&self) -> usize {
+        self.start + DIVISOR_SUM.len()
+    }
+
+    /// Leaves the registers as running the loop from its start would have.
+    fn skip(&self, registers: &mut [usize]) {
+        let n = registers[self.registers[N]];
+        registers[self.registers[SUM]] += sum_of_divisors(n);
+        // Both counters end up just past n, or past 1 if n is 0 as they always go round once
+        registers[self.registers[I]] = n.max(1) + 1;
+        registers[self.registers[J]] = n.max(1) + 1;
+        registers[self.registers[TEST]] = 1;
+    }
+}
+
+/// Matches arguments against the loop's pattern, with the loop starting at `start`.
+struct Matcher {
+    start: usize,
+    ip_register: usize,
+}
+
+impl Matcher {
+    fn bind(&self, arg: Arg, value: usize, bindings: &mut Bindings) -> bool {
+        match arg {
+            Arg::Register(name) => match bindings[name] {
+                Some(register) => register == value,
+                None if value == self.ip_register || bindings.contains(&Some(value)) => false,
+                None => {
+                    bindings[name] = Some(value);
+                    true
+                }
+            },
+            Arg::Ip => value == self.ip_register,
+            Arg::Value(expected) => value == expected,
+            Arg::Jump(offset) => value + 1 == self.start + offset,
+            Arg::Ignored => true,
+        }
+    }
+}
+
+fn sum_of_divisors(n: usize) -> usize {
+    (1..)
+        .take_while(|d| d * d <= n)
+        .filter(|d| n.is_multiple_of(*d))
+        .map(|d| if d * d == n { d } else { d + n / d })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The same loop as the puzzle input, with the registers shuffled around and the
+    // arguments of the commutative ops swapped, summing the divisors of 12
+    const SHUFFLED: &str = "#ip 4\nseti 12 0 5\nseti 1 0 1\nseti 1 0 2\nmulr 2 1 3\neqrr 5 3 3\n\
+                            addr 4 3 4\naddi 4 1 4\naddr 0 1 0\naddi 2 1 2\ngtrr 2 5 3\naddr 3 4 4\n\
+                            seti 2 0 4\naddi 1 1 1\ngtrr 1 5 3\naddr 4 3 4\nseti 1 0 4";
+
+    #[test]
+    fn finds_the_loop_whichever_registers_it_uses() {
+        let program = Program::parse(SHUFFLED, NUM_REGISTERS).unwrap();
+        assert_eq!(
+            DivisorSum::find(&program),
+            Some(DivisorSum {
+                start: 1,
+                registers: [1, 2, 3, 5, 0],
+            })
+        );
+        let example = Program::parse(include_str!("../fixtures/example.txt"), NUM_REGISTERS).unwrap();
+        assert_eq!(DivisorSum::find(&example), None);
+    }
+
+    #[test]
+    fn skipping_the_loop_matches_running_it() {
+        let program = Program::parse(SHUFFLED, NUM_REGISTERS).unwrap();
+        let compiled = program.compile();
+        for &register_0 in &[0, 5] {
+            let mut machine = Machine::for_program(&compiled);
+            machine.registers_mut()[0] = register_0;
+            machine.run(&compiled);
+            assert_eq!(run_summing_divisors(&program, register_0), machine.registers()[0]);
+        }
+        assert_eq!(run_summing_divisors(&program, 0), 1 + 2 + 3 + 4 + 6 + 12);
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use aoc_common::{Params, Solution};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+  const DAY: u32 = 2;
+
+  type Input = Vec<String>;
+  type Part1 = i32;
+  type Part2 = String;
+
+  fn parse(input: &str, _params: &Params) -> Result<Vec<String>, Box<dyn Error>> {
+    Ok(input.lines().map(String::from).collect())
+  }
+
+  fn part1(words: &Vec<String>) -> i32 {
+    let result = words.iter()
+      // get a count of each character in each line
+      .map(|line| line.chars()
+        .fold(HashMap::new(), |mut char_counts: HashMap<char, i32>, new_char| {
+            let count = char_counts.get(&new_char).unwrap_or(&0).to_owned() + 1;
+            char_counts.insert(new_char, count);
+            char_counts
+        }))
+      // Get if the line has a character that appears exactly 2 or 3 times
+      .map(|char_count| (char_count.values().any(|&x| x == 2), char_count.values().any(|&x| x == 3)))
+      .fold((0, 0), | (mut contains_two_count, mut contains_three_count), (contains_two, contains_three)| {
+        if contains_two {
+          contains_two_count +=1;
+        }
+        if contains_three {
+          contains_three_count +=1;
+        }
+        (contains_two_count, contains_three_count)
+      });
+    // Count the number of lines which have a max char count needed for the checksum operation
+    result.0 * result.1
+  }
+
+  fn part2(words: &Vec<String>) -> String {
+    find_close_words(words).expect("No two words differ by exactly one character")
+  }
+}
+
+fn find_close_words (words: &[String]) -> Option<String>{
+  for i in 0..words.len() {
+    for j in i..words.len() {
+      // Assumption: words are always the same length
+      if words[i] == words[j] || words[i].len() != words[j].len() {
+        continue;
+      }
+      let mut diff_count = 0;
+      let mut word1_chars = words[i].chars();
+      let mut word2_chars = words[j].chars();
+      let mut resulting_word = String::new();
+
+      while diff_count < 2 {
+        let word1_char = word1_chars.next();
+        let word2_char = word2_chars.next();
+
+        if let (Some(word1_char), Some(word2_char)) = (word1_char, word2_char) {
+          if word1_char != word2_char {
+            diff_count += 1;
+
+          } else {
+            resulting_word.push(word1_char);
+          }
+        } else {
+          break;
+        }
+      }
+
+      if diff_count == 1 {
+        return Some(resulting_word);
+      }
+    }
+  }
+  None
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn read(input: &str) -> Vec<String> {
+    Day2::parse(input, &Params::new()).unwrap()
+  }
+
+  #[test]
+  fn example_checksum() {
+    let words = read(include_str!("../fixtures/example-1.txt"));
+    assert_eq!(words.len(), 7);
+    assert_eq!(Day2::part1(&words), 12);
+    // Two letters the same in four words, and three the same in three words
+    assert_eq!(Day2::part1(&read("bababc\nabbcde\nabcccd\naabcdd\nabcdee\nababab")), 4 * 3);
+  }
+
+  #[test]
+  fn example_close_words() {
+    let words = read(include_str!("../fixtures/example-2.txt"));
+    assert_eq!(find_close_words(&words), Some(String::from("fgij")));
+    assert_eq!(find_close_words(&read("abcde\naxcye")), None);
+  }
+}
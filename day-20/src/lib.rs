@@ -0,0 +1,181 @@
+use std::collections::{BTreeSet, HashMap};
+use std::error::Error;
+
+use aoc_common::{InputError, Params, Point, Solution};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+
+    type Input = Vec<Room>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str, _params: &Params) -> Result<Vec<Room>, Box<dyn Error>> {
+        Ok(get_paths_from_input(input)?)
+    }
+
+    fn part1(rooms: &Vec<Room>) -> usize {
+        rooms
+            .iter()
+            .map(|room| room.distance_from_start)
+            .max()
+            .expect("No rooms found")
+    }
+
+    fn part2(rooms: &Vec<Room>) -> usize {
+        rooms
+            .iter()
+            .filter(|room| room.distance_from_start >= 1000)
+            .count()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Room {
+    position: Point,
+    distance_from_start: usize,
+}
+
+/// The rooms that each room has doors to.
+type Doors = HashMap<Point, Vec<Point>>;
+
+#[derive(Debug)]
+struct Branch {
+    starting_points: BTreeSet<Point>,
+    end_points: BTreeSet<Point>,
+}
+
+// Follows every path through the input to find the doors, then how far each room is from the start
+fn get_paths_from_input(input: &str) -> Result<Vec<Room>, InputError> {
+    let start = Point::new(0, 0);
+    let doors = get_doors_from_input(input)?;
+    let search = aoc_common::bfs(start, |room| doors.get(room).cloned().unwrap_or_default(), |_| false);
+    Ok(search
+        .distances()
+        .map(|(&position, distance)| Room {
+            position,
+            distance_from_start: distance as usize,
+        })
+        .collect())
+}
+
+fn get_doors_from_input(input: &str) -> Result<Doors, InputError> {
+    // The rooms that the paths we are currently following have reached
+    let mut path_heads: BTreeSet<Point> = vec![Point::new(0, 0)].into_iter().collect();
+
+    // Saves all path heads upon starting a group of branching paths in a stack (for nested groups).
+    // When we start trying a new branch, we need to clone these as a starting point
+    // When the current group is completed, we pop the top of the stack
+    let mut branches = Vec::new();
+    // Where each group in `branches` was opened, in case it's never closed
+    let mut group_openings = Vec::new();
+
+    let mut doors = Doors::new();
+
+    for (line, offset, next_char) in aoc_common::lines(input)
+        .flat_map(|line| line.text.char_indices().map(move |(offset, c)| (line, offset, c)))
+    {
+        let char_text = &line.text[offset..offset + next_char.len_utf8()];
+        match next_char {
+            'N' => step(&mut path_heads, &mut doors, Point::up),
+            'E' => step(&mut path_heads, &mut doors, Point::right),
+            'S' => step(&mut path_heads, &mut doors, Point::down),
+            'W' => step(&mut path_heads, &mut doors, Point::left),
+            '(' => {
+                // Initialise branch
+                branches.push(Branch {
+                    starting_points: path_heads.clone(),
+                    end_points: BTreeSet::new(),
+                });
+                group_openings.push((line, char_text));
+            },
+            '|' => {
+                let branch = branches
+                    .last_mut()
+                    .ok_or_else(|| line.error_at(char_text, "Unexpected '|' outside of a group"))?;
+                // Add all end points for this branch
+                branch.end_points.extend(path_heads);
+                // Reset path heads to search from start of branch
+                path_heads = branch.starting_points.clone();
+            }
+            ')' => {
+                let mut branch = branches
+                    .pop()
+                    .ok_or_else(|| line.error_at(char_text, "Unexpected ')' without a matching '('"))?;
+                group_openings.pop();
+                // Carry on from wherever any of the branches ended up
+                branch.end_points.extend(path_heads);
+                path_heads = branch.end_points;
+            }
+            '^' | '$' => (),
+            _ => return Err(line.error_at(char_text, format!("Unexpected character {:?}", next_char))),
+        }
+    }
+    if let Some((line, opening)) = group_openings.pop() {
+        return Err(line.error_at(opening, "This '(' is never closed"));
+    }
+    Ok(doors)
+}
+
+// Moves every path head through the door in the given direction
+fn step(path_heads: &mut BTreeSet<Point>, doors: &mut Doors, direction: fn(Point) -> Point) {
+    *path_heads = path_heads
+        .iter()
+        .map(|&room| {
+            let next_room = direction(room);
+            add_door(doors, room, next_room);
+            add_door(doors, next_room, room);
+            next_room
+        })
+        .collect();
+}
+
+fn add_door(doors: &mut Doors, from: Point, to: Point) {
+    let rooms = doors.entry(from).or_default();
+    if !rooms.contains(&to) {
+        rooms.push(to);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn furthest_room(example: &str) -> usize {
+        get_paths_from_input(example)
+            .unwrap()
+            .iter()
+            .map(|room| room.distance_from_start)
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn simple_path_rooms() {
+        let example = include_str!("../fixtures/example-1.txt");
+        let mut rooms: Vec<(i32, i32, usize)> = get_paths_from_input(example)
+            .unwrap()
+            .into_iter()
+            .map(|room| (room.position.x, room.position.y, room.distance_from_start))
+            .collect();
+        rooms.sort();
+        assert_eq!(rooms, vec![(-1, -1, 2), (-1, 0, 1), (0, -1, 3), (0, 0, 0)]);
+    }
+
+    #[test]
+    fn furthest_rooms() {
+        assert_eq!(furthest_room(include_str!("../fixtures/example-1.txt")), 3);
+        assert_eq!(furthest_room(include_str!("../fixtures/example-2.txt")), 10);
+        assert_eq!(furthest_room(include_str!("../fixtures/example-3.txt")), 18);
+        assert_eq!(furthest_room(include_str!("../fixtures/example-4.txt")), 23);
+        assert_eq!(furthest_room(include_str!("../fixtures/example-5.txt")), 31);
+    }
+
+    #[test]
+    fn paths_that_loop_back_take_the_shorter_way() {
+        // The last room is next to the start, through the door the loop comes back by
+        assert_eq!(furthest_room("^NESW$"), 2);
+    }
+}
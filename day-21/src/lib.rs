@@ -0,0 +1,149 @@
+use std::error::Error;
+use std::iter;
+
+use aoc_common::{Params, Solution};
+use elfcode::{Compiled, Instruction, Machine, Op, Program};
+
+pub struct Day21;
+
+const NUM_REGISTERS: usize = 6;
+
+/// A program that halts once register 0 matches another register, along with where it
+/// compares them.
+struct HaltingProgram {
+    program: Compiled,
+    address: usize,
+    register: usize,
+}
+
+impl HaltingProgram {
+    /// Finds the `eqrr` that compares register 0 to another register, checking that nothing
+    /// writes register 0 and nothing else reads it, so that the comparison is the only way its
+    /// value can change what the program does.
+    fn analyse(program: &Program) -> Result<HaltingProgram, String> {
+        let (address, register) = program
+            .instructions
+            .iter()
+            .enumerate()
+            .find_map(|(address, instruction)| match *instruction {
+                Instruction { op: Op::Eqrr, a: 0, b, .. } if b != 0 => Some((address, b)),
+                Instruction { op: Op::Eqrr, a, b: 0, .. } if a != 0 => Some((address, a)),
+                _ => None,
+            })
+            .ok_or("The program never compares register 0 to another register")?;
+        if program.ip_register == Some(0) {
+            return Err(String::from("The instruction pointer is bound to register 0"));
+        }
+        for (other_address, instruction) in program.instructions.iter().enumerate() {
+            let reads_0 = (instruction.op.a_is_register() && instruction.a == 0)
+                || (instruction.op.b_is_register() && instruction.b == 0);
+            if instruction.c == 0 {
+                return Err(format!("The instruction at {} ({}) writes register 0", other_address, instruction));
+            }
+            if reads_0 && other_address != address {
+                return Err(format!(
+                    "The instruction at {} ({}) reads register 0 other than to compare it",
+                    other_address, instruction
+                ));
+            }
+        }
+        Ok(HaltingProgram {
+            program: program.compile(),
+            address,
+            register,
+        })
+    }
+
+    /// The values that register 0 is compared against, in order, while it matches none of them.
+    fn compared_values(&self) -> impl Iterator<Item = usize> + '_ {
+        let mut machine = Machine::for_program(&self.program);
+        iter::from_fn(move || {
+            if !machine.run_until(&self.program, |ip, _| ip == self.address) {
+                return None;
+            }
+            let value = machine.registers()[self.register];
+            // Step past the comparison, so that the next run carries on from here
+            machine.step(&self.program);
+            Some(value)
+        })
+    }
+}
+
+/// The values a program compares register 0 against, in order, up to the last one before they
+/// start repeating.
+pub struct ComparedValues {
+    values: Vec<usize>,
+}
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+
+    type Input = ComparedValues;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    // Both parts need the values up to the first repeat, so run the program here, where a
+    // program that halts before then can be reported rather than panicking in a part
+    fn parse(input: &str, _params: &Params) -> Result<ComparedValues, Box<dyn Error>> {
+        let program = HaltingProgram::analyse(&Program::parse(input, NUM_REGISTERS)?)?;
+        let (cycle, mut values) = aoc_common::find_cycle(program.compared_values())
+            .ok_or("The program halted before the values it compares register 0 to repeated")?;
+        values.truncate(cycle.start + cycle.period);
+        Ok(ComparedValues { values })
+    }
+
+    // The program halts when register 0 matches the value it is compared against (see
+    // input/input1-notes.txt), so the first such value halts the program soonest
+    fn part1(compared: &ComparedValues) -> usize {
+        compared.values[0]
+    }
+
+    // The values compared against register 0 eventually repeat, so the last one before they
+    // do halts the program latest
+    fn part2(compared: &ComparedValues) -> usize {
+        aoc_common::record("unique_values", compared.values.len() as i64);
+        compared.values[compared.values.len() - 1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_values_register_0_is_compared_against() {
+        // Counts up in threes modulo 8, halting if register 0 matches one more than the count
+        let input = "#ip 5\nseti 0 0 4\naddi 1 3 1\nbani 1 7 1\naddi 1 1 3\neqrr 0 3 2\naddr 2 5 5\nseti 0 0 5";
+        let program = HaltingProgram::analyse(&Program::parse(input, NUM_REGISTERS).unwrap()).unwrap();
+        assert_eq!((program.address, program.register), (4, 3));
+        let values: Vec<usize> = program.compared_values().take(9).collect();
+        assert_eq!(values, vec![4, 7, 2, 5, 8, 3, 6, 1, 4]);
+        let compared = Day21::parse(input, &Params::new()).unwrap();
+        assert_eq!(Day21::part1(&compared), 4);
+        assert_eq!(Day21::part2(&compared), 1);
+    }
+
+    #[test]
+    fn needs_the_compared_values_to_repeat() {
+        // Compares register 0 once, then runs off the end
+        let error = Day21::parse("#ip 5
+eqrr 0 1 2", &Params::new()).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "The program halted before the values it compares register 0 to repeated"
+        );
+    }
+
+    #[test]
+    fn needs_a_comparison_against_register_0() {
+        let error = Day21::parse("#ip 5\neqrr 1 2 3\neqri 0 7 1", &Params::new()).err().unwrap();
+        assert_eq!(error.to_string(), "The program never compares register 0 to another register");
+        let error = Day21::parse("#ip 5\neqrr 0 1 2\nseti 7 0 0", &Params::new()).err().unwrap();
+        assert_eq!(error.to_string(), "The instruction at 1 (seti 7 0 0) writes register 0");
+        let error = Day21::parse("#ip 5\neqrr 0 1 2\naddr 0 1 3", &Params::new()).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "The instruction at 1 (addr 0 1 3) reads register 0 other than to compare it"
+        );
+    }
+}
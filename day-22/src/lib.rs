@@ -0,0 +1,185 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+
+use aoc_common::{Param, Params, Solution};
+
+pub struct Day22;
+
+pub struct Cave {
+    depth: i32,
+    target_coordinates: (i32, i32),
+}
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+    const TAKES_INPUT: bool = false;
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "depth",
+            description: "the cave depth",
+        },
+        Param {
+            name: "target_x",
+            description: "the target's x coordinate",
+        },
+        Param {
+            name: "target_y",
+            description: "the target's y coordinate",
+        },
+    ];
+
+    type Input = Cave;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(_input: &str, params: &Params) -> Result<Cave, Box<dyn Error>> {
+        let depth: i32 = params.get("depth")?;
+        let target_x: i32 = params.get("target_x")?;
+        let target_y: i32 = params.get("target_y")?;
+        Ok(Cave {
+            depth,
+            target_coordinates: (target_x, target_y),
+        })
+    }
+
+    fn part1(cave: &Cave) -> i32 {
+        let mut erosion_levels = HashMap::new();
+        let mut risk_level = 0;
+
+        for y in 0..cave.target_coordinates.1 + 1 {
+            for x in 0..cave.target_coordinates.0 + 1 {
+                let erosion_level =
+                    get_erosion_level((x, y), cave.target_coordinates, cave.depth, &mut erosion_levels);
+                risk_level += match get_region_type(erosion_level) {
+                    RegionType::Rocky => 0,
+                    RegionType::Wet => 1,
+                    RegionType::Narrow => 2,
+                };
+            }
+        }
+        risk_level
+    }
+
+    fn part2(cave: &Cave) -> i32 {
+        part_2(cave.target_coordinates, cave.depth).expect("Unable to reach the target")
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+struct State {
+    position: (i32, i32),
+    equipment: Equipment,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum RegionType {
+    Rocky,
+    Wet,
+    Narrow
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+enum Equipment {
+    Nothing,
+    ClimbingGear,
+    Torch,
+}
+
+fn can_traverse(region: &RegionType) -> HashSet<Equipment> {
+    match region {
+        RegionType::Rocky => vec![Equipment::ClimbingGear, Equipment::Torch].into_iter().collect(),
+        RegionType::Wet => vec![Equipment::ClimbingGear, Equipment::Nothing].into_iter().collect(),
+        RegionType::Narrow => vec![Equipment::Torch, Equipment::Nothing].into_iter().collect(),
+    }
+}
+
+fn get_erosion_level((x,y): (i32, i32), target_coordinates: (i32, i32), depth: i32, erosion_levels: &mut HashMap<(i32, i32), i32>) -> i32 {
+    if let Some(existing_erosion_level) = erosion_levels.get(&(x, y)) {
+        *existing_erosion_level
+    } else {
+        let index = match (x, y) {
+            (0,0) => 0,
+            coordinates if coordinates == target_coordinates => 0,
+            (x, 0) => x * 16807 ,
+            (0, y) => y * 48271 ,
+            (x, y) => {
+                get_erosion_level((x-1, y), target_coordinates, depth, erosion_levels) * get_erosion_level((x, y-1), target_coordinates, depth, erosion_levels) 
+            }
+        };
+        let erosion_level = (index + depth) % 20183;
+        erosion_levels.insert((x,y), erosion_level);
+        erosion_level
+    }
+}
+
+fn get_region_type(erosion_level: i32) -> RegionType {
+    match erosion_level % 3 {
+        0 => RegionType::Rocky,
+        1 => RegionType::Wet,
+        2 => RegionType::Narrow,
+        _ => panic!("Unknown region type"),
+    }
+}
+
+// Returns: the fewest minutes it takes to reach the target with the torch equipped
+fn part_2(target_coordinates: (i32, i32), depth: i32) -> Option<i32> {
+    let mut erosion_levels = HashMap::<(i32, i32), i32>::new();
+    let mut region_type = |position| {
+        get_region_type(get_erosion_level(position, target_coordinates, depth, &mut erosion_levels))
+    };
+    let start = State {
+        position: (0, 0),
+        equipment: Equipment::Torch,
+    };
+    let target = State {
+        position: target_coordinates,
+        equipment: Equipment::Torch,
+    };
+
+    let search = aoc_common::astar(
+        start,
+        |current_state: &State| {
+            let position = current_state.position;
+            let mut next_states = Vec::new();
+            let new_positions = vec![
+                (position.0-1, position.1),
+                (position.0+1, position.1),
+                (position.0, position.1-1),
+                (position.0, position.1+1),
+            ];
+            for position in new_positions.into_iter().filter(|(x, y)| *x >= 0 && *y >= 0) {
+                if can_traverse(&region_type(position)).contains(&current_state.equipment) {
+                    next_states.push((State { position, equipment: current_state.equipment.clone() }, 1));
+                }
+            }
+            let mut switches = can_traverse(&region_type(position));
+            // The torch has to be equipped at the target, whatever the region
+            if position == target_coordinates {
+                switches.insert(Equipment::Torch);
+            }
+            for equipment in switches.into_iter().filter(|equipment| *equipment != current_state.equipment) {
+                next_states.push((State { position, equipment }, 7));
+            }
+            next_states
+        },
+        |state| {
+            let distance = (state.position.0 - target_coordinates.0).abs() + (state.position.1 - target_coordinates.1).abs();
+            let switch = if state.equipment == Equipment::Torch { 0 } else { 7 };
+            (distance + switch) as u64
+        },
+        |state| *state == target,
+    );
+
+    let minutes = search.distance(&target)?;
+    if aoc_common::is_verbose() {
+        for state in search.path(&target).unwrap() {
+            aoc_common::diagnostic!(
+                "{:>4} minutes: at {:?} with {:?}",
+                search.distance(&state).unwrap(),
+                state.position,
+                state.equipment
+            );
+        }
+    }
+    Some(minutes as i32)
+}
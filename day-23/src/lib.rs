@@ -4,43 +4,46 @@ extern crate lazy_static;
 
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
-use std::env;
-use std::fs::File;
-use std::io::prelude::*;
-use std::io::BufReader;
-use std::process;
+use std::error::Error;
 
+use aoc_common::{InputError, Line, Params, Solution};
 use regex::Regex;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    let filename = &args[1];
-    let file = File::open(filename).expect("Unable to open file");
-    let (lines, errs): (Vec<_>, Vec<_>) = BufReader::new(file).lines().partition(Result::is_ok);
-    if !errs.is_empty() {
-        for err in errs.into_iter().filter_map(Result::err) {
-            eprintln!("{}", err);
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+
+    type Input = Vec<Nanobot>;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(input: &str, _params: &Params) -> Result<Vec<Nanobot>, Box<dyn Error>> {
+        let nanobots = aoc_common::collect_results(aoc_common::lines(input).map(read_nanobot_from_line))?;
+        if nanobots.is_empty() {
+            return Err("The input has no nanobots in it".into());
         }
-        process::exit(1);
+        Ok(nanobots)
     }
-    let (nanobots, errs): (Vec<_>, Vec<_>) = lines
-        .into_iter()
-        .filter_map(Result::ok)
-        .map(read_nanobot_from_line)
-        .partition(Result::is_ok);
-    if !errs.is_empty() {
-        for err in errs.into_iter().filter_map(Result::err) {
-            eprintln!("{}", err);
-        }
-        process::exit(1);
+
+    fn part1(nanobots: &Vec<Nanobot>) -> usize {
+        let biggest_nanobot = nanobots
+            .iter()
+            .max_by_key(|nanobot| nanobot.radius)
+            .expect("Parsing rejects inputs without nanobots");
+        nanobots
+            .iter()
+            .filter(|nanobot| distance_to(&nanobot.position, &biggest_nanobot.position) <= biggest_nanobot.radius)
+            .count()
+    }
+
+    fn part2(nanobots: &Vec<Nanobot>) -> i32 {
+        part_2(nanobots)
     }
-    let nanobots: Vec<Nanobot> = nanobots.into_iter().filter_map(Result::ok).collect();
-//    let min_radius = nanobots.iter().min_by_key(|nanobot| nanobot.radius).unwrap().radius;
-    let result = part_2(&nanobots);
-    println!("{:?}", result);
 }
-fn part_2(nanobots: &Vec<Nanobot>) -> i32 {
-    let bounds = nanobots.iter().fold((std::i32::MAX, std::i32::MIN, std::i32::MAX, std::i32::MIN, std::i32::MAX, std::i32::MIN), |mut bounds, nanobot| {
+
+fn part_2(nanobots: &[Nanobot]) -> i32 {
+    let bounds = nanobots.iter().fold((i32::MAX, i32::MIN, i32::MAX, i32::MIN, i32::MAX, i32::MIN), |mut bounds, nanobot| {
         bounds.0 = i32::min(bounds.0, nanobot.position.0);
         bounds.1 = i32::max(bounds.1, nanobot.position.0);
         bounds.2 = i32::min(bounds.2, nanobot.position.1);
@@ -54,8 +57,6 @@ fn part_2(nanobots: &Vec<Nanobot>) -> i32 {
     let y_length = bounds.3 - bounds.2;
     let z_length = bounds.5 - bounds.4;
 
-    println!("{:?}", bounds);
-//    let mut box_size = min_radius;
     let initial_search_cube = SearchCube {
         centre_point: (
             bounds.0 + x_length/2,
@@ -73,17 +74,12 @@ fn part_2(nanobots: &Vec<Nanobot>) -> i32 {
     search_regions.push((nanobots.len(), initial_search_cube));
 
     loop {
-        println!("FINDING NEXT CUBE");
-        for s in search_regions.iter() {
-            println!("{:?}", s);
-        }
-        let (num_bots, next_cube) = search_regions.pop().unwrap();
-        println!("CHOSEN_CUBE:\n{}, {:?}\n\n", num_bots, next_cube);
+        let (_num_bots, next_cube) = search_regions.pop().unwrap();
         match next_cube.subdivide() {
             Some(smaller_cubes) => {
                 smaller_cubes.into_iter()
                     .for_each(|cube|
-                        search_regions.push((cube.get_intersecting_nanobots(&nanobots), cube))
+                        search_regions.push((cube.get_intersecting_nanobots(nanobots), cube))
                     );
             },
             None => return distance_to(&next_cube.centre_point, &(0,0,0)),
@@ -93,7 +89,7 @@ fn part_2(nanobots: &Vec<Nanobot>) -> i32 {
 
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
-struct Nanobot {
+pub struct Nanobot {
     position: (i32, i32, i32),
     radius: i32,
 }
@@ -128,7 +124,7 @@ impl SearchCube {
         }
     }
 
-    fn get_intersecting_nanobots(&self, nanobots: &Vec<Nanobot>) -> usize {
+    fn get_intersecting_nanobots(&self, nanobots: &[Nanobot]) -> usize {
         nanobots.iter()
             .filter(|nanobot|
                 distance_to(&nanobot.position, &self.centre_point) <= (nanobot.radius + ((self.side_length / 2) * 3 ))
@@ -152,32 +148,51 @@ fn distance_to(pos: &(i32, i32, i32), other: &(i32, i32, i32)) -> i32 {
     i32::abs(pos.0 - other.0) + i32::abs(pos.1 - other.1) + i32::abs(pos.2 - other.2)
 }
 
-fn read_nanobot_from_line(line: String) -> Result<Nanobot, String> {
+fn read_nanobot_from_line(line: Line) -> Result<Nanobot, InputError> {
     lazy_static! {
         static ref nanobot_regex: Regex =
             Regex::new(r"pos=<(-?\d+),(-?\d+),(-?\d+)>, r=(\d+)").unwrap();
     }
-    if let Some(captures) = nanobot_regex.captures(&line) {
-        let x = captures[1]
-            .parse::<i32>()
-            .expect("Unable to parse x value of nanobot");
-        let y = captures[2]
-            .parse::<i32>()
-            .expect("Unable to parse y value of nanobot");
-        let z = captures[3]
-            .parse::<i32>()
-            .expect("Unable to parse z value of nanobot");
-        let radius = captures[4]
-            .parse::<i32>()
-            .expect("Unable to parse radius value of nanobot");
-        Ok(Nanobot {
-            position: (x, y, z),
-            radius,
-        })
-    } else {
-        Err(String::from(format!(
-            "Unable to match regex for line {}",
-            line
-        )))
+    let captures = line.captures(&nanobot_regex, "a nanobot like \"pos=<0,0,0>, r=4\"")?;
+    let x = line.parse::<i32>(&captures[1])?;
+    let y = line.parse::<i32>(&captures[2])?;
+    let z = line.parse::<i32>(&captures[3])?;
+    let radius = line.parse::<i32>(&captures[4])?;
+    Ok(Nanobot {
+        position: (x, y, z),
+        radius,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(input: &str) -> Vec<Nanobot> {
+        Day23::parse(input, &Params::new()).unwrap()
+    }
+
+    #[test]
+    fn example_nanobots_in_range() {
+        let nanobots = read(include_str!("../fixtures/example-1.txt"));
+        let distances: Vec<i32> = nanobots.iter().map(|nanobot| distance_to(&nanobot.position, &(0, 0, 0))).collect();
+        assert_eq!(distances, vec![0, 1, 4, 2, 5, 3, 3, 4, 5]);
+        assert_eq!(Day23::part1(&nanobots), 7);
+    }
+
+    #[test]
+    fn example_closest_best_position() {
+        let nanobots = read(include_str!("../fixtures/example-2.txt"));
+        let in_range = nanobots
+            .iter()
+            .filter(|nanobot| distance_to(&nanobot.position, &(12, 12, 12)) <= nanobot.radius)
+            .count();
+        assert_eq!(in_range, 5);
+        let cube = SearchCube {
+            centre_point: (0, 0, 0),
+            side_length: 4,
+        };
+        assert_eq!(cube.subdivide().map(|cubes| cubes.len()), Some(8));
+        assert_eq!(part_2(&nanobots), 36);
     }
 }
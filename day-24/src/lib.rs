@@ -0,0 +1,331 @@
+#[macro_use]
+extern crate lazy_static;
+extern crate regex;
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+
+use aoc_common::{InputError, Line, Lines, ParseErrors, Params, Solution};
+use regex::Regex;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+
+    type Input = Vec<ArmyGroup>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str, _params: &Params) -> Result<Vec<ArmyGroup>, Box<dyn Error>> {
+        Ok(read_armies_from_input(aoc_common::lines(input))?)
+    }
+
+    fn part1(initial_state: &Vec<ArmyGroup>) -> i32 {
+        units_left(&fight(initial_state.clone()))
+    }
+
+    fn part2(initial_state: &Vec<ArmyGroup>) -> i32 {
+        let mut boost = 0;
+        loop {
+            let army_groups = fight(boost_immune_system(initial_state, boost));
+
+            let winning_teams: HashSet<String> = army_groups.iter()
+                .filter(|army_group| army_group.num_units > 0)
+                .map(|army_group| army_group.team.clone())
+                .collect();
+
+            aoc_common::diagnostic!(
+                "Boost {}: {:?} wins with {} units left",
+                boost,
+                winning_teams,
+                units_left(&army_groups)
+            );
+            if winning_teams.len() == 1 && winning_teams.iter().next() == Some(&"Immune System".to_string()) {
+                aoc_common::record("boost", i64::from(boost));
+                return units_left(&army_groups);
+            } else {
+                boost += 1;
+            }
+        }
+    }
+}
+
+fn boost_immune_system(army_groups: &[ArmyGroup], boost: i32) -> Vec<ArmyGroup> {
+    army_groups
+        .iter()
+        .cloned()
+        .map(|army_group| {
+            if army_group.team == "Immune System" {
+                ArmyGroup {
+                    attack_damage: army_group.attack_damage + boost,
+                    ..army_group
+                }
+            } else {
+                army_group
+            }
+        })
+        .collect()
+}
+
+// Fights until only one team is left, or until neither team is able to kill any more units
+fn fight(mut army_groups: Vec<ArmyGroup>) -> Vec<ArmyGroup> {
+    loop {
+        if !fight_round(&mut army_groups) {
+            return army_groups;
+        }
+        aoc_common::diagnostic!("{} units left", units_left(&army_groups));
+
+        let teams_left: HashSet<&String> = army_groups.iter()
+            .filter(|army_group| army_group.num_units > 0)
+            .map(|army_group| &army_group.team)
+            .collect();
+
+        if teams_left.len() <= 1 {
+            return army_groups;
+        }
+    }
+}
+
+// Returns: whether any units died during the round
+fn fight_round(army_groups: &mut [ArmyGroup]) -> bool {
+    // select targets
+    let mut targets: HashMap<usize, usize> = HashMap::new();
+    let mut sorted_army_groups: Vec<usize> = (0..army_groups.len()).collect();
+    sorted_army_groups.sort_by_key(|&index| {
+        let army_group = army_groups.get(index).unwrap();
+        (-army_group.effective_power(), -army_group.initiative)
+    });
+
+    for &index in sorted_army_groups.iter() {
+        let army_group = army_groups.get(index).unwrap();
+        if army_group.num_units > 0 {
+            let possible_targets: Vec<(usize, &ArmyGroup)> = army_groups.iter()
+                .enumerate()
+                .filter(|(_other_index, other)| other.team != army_group.team)
+                .filter(|(_other_index, other)| other.num_units > 0)
+                .filter(|(other_index, _other)| !targets.values().collect::<Vec<_>>().contains(&other_index))
+                .collect();
+            if let Some(target) = army_group.select_target(&possible_targets) {
+                targets.insert(index, target);
+            }
+        }
+    }
+
+    // attack targets
+    sorted_army_groups.sort_by_key(|&index| {
+        let army_group = army_groups.get(index).unwrap();
+        -army_group.initiative
+    });
+
+    let mut any_units_died = false;
+    for &index in sorted_army_groups.iter() {
+        let army_group = army_groups.get(index).unwrap();
+        if army_group.num_units > 0 {
+            if let Some(&target_index) = targets.get(&index) {
+                let target = army_groups.get(target_index).unwrap();
+                let damage = army_group.damage_to(target);
+                let units_lost = i32::min(target.num_units, damage / target.hit_points);
+                if units_lost > 0 {
+                    any_units_died = true;
+                }
+                let target = army_groups.get_mut(target_index).unwrap();
+                target.num_units -= units_lost;
+            }
+        }
+    }
+    any_units_died
+}
+
+fn units_left(army_groups: &[ArmyGroup]) -> i32 {
+    army_groups.iter()
+        .filter(|army_group| army_group.num_units > 0)
+        .map(|army_group| army_group.num_units)
+        .sum()
+}
+
+// Lists the units left in each group, in the same format as the puzzle
+fn _format_armies(army_groups: &[ArmyGroup]) -> String {
+    let mut teams: Vec<&String> = Vec::new();
+    for army_group in army_groups {
+        if !teams.contains(&&army_group.team) {
+            teams.push(&army_group.team);
+        }
+    }
+    let mut result = String::new();
+    for team in teams {
+        result += &format!("{}:\n", team);
+        let groups_left: Vec<(usize, &ArmyGroup)> = army_groups.iter()
+            .filter(|army_group| army_group.team == *team)
+            .enumerate()
+            .filter(|(_index, army_group)| army_group.num_units > 0)
+            .collect();
+        if groups_left.is_empty() {
+            result += "No groups remain.\n";
+        }
+        for (index, army_group) in groups_left {
+            result += &format!("Group {} contains {} units\n", index + 1, army_group.num_units);
+        }
+    }
+    result
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ArmyGroup {
+    num_units: i32,
+    hit_points: i32,
+    team: String,
+    attack_damage: i32,
+    attack_type: String,
+    initiative: i32,
+    weaknesses: Vec<String>,
+    immunities: Vec<String>,
+}
+
+impl ArmyGroup {
+
+    fn select_target(&self, possible_targets: &Vec<(usize, &ArmyGroup)>) -> Option<usize> {
+        possible_targets
+            .iter()
+            .filter(|(_index, army_group)|
+                !army_group
+                    .immunities
+                    .contains(&self.attack_type)
+            )
+            .max_by_key(|(_index, army_group)|
+                (
+                    self.damage_to(army_group),
+                    army_group.effective_power(),
+                    army_group.initiative,
+                )
+            )
+            .map(|(index, _army_group)| *index)
+    }
+
+    fn damage_to(&self, other: &ArmyGroup) -> i32 {
+        let damage_multiplier;
+        if other.weaknesses.contains(&self.attack_type) {
+            damage_multiplier = 2;
+        } else if other.immunities.contains(&self.attack_type) {
+            damage_multiplier = 0;
+        } else {
+            damage_multiplier = 1;
+        }
+        damage_multiplier * self.num_units * self.attack_damage
+    }
+
+    fn effective_power(&self) -> i32 {
+        self.num_units * self.attack_damage
+    }
+}
+
+fn read_armies_from_input(lines: Lines) -> Result<Vec<ArmyGroup>, ParseErrors> {
+    lazy_static! {
+        static ref army_name_regex: Regex = Regex::new(r"^(?P<army_name>[\w\s]+):$").unwrap();
+    }
+    let mut current_army = None;
+    aoc_common::collect_results(lines.filter_map(|line| {
+        if line.text.is_empty() {
+            None
+        } else if let Some(captures) = army_name_regex.captures(line.text) {
+            current_army = captures
+                .name("army_name")
+                .map(|army_name| army_name.as_str().to_string());
+            None
+        } else {
+            Some(match &current_army {
+                Some(army) => read_army_group_from_line(line, army.clone()),
+                None => Err(line.error("Expected an army name before its groups")),
+            })
+        }
+    }))
+}
+
+fn read_army_group_from_line(line: Line, current_army: String) -> Result<ArmyGroup, InputError> {
+    lazy_static! {
+        static ref army_group_regex: Regex = Regex::new(r"^(?P<num_units>\d+) units each with (?P<hit_points>\d+) hit points (\((?P<weaknesses_and_immunities>.*)\) )?with an attack that does (?P<attack_damage>\d+) (?P<attack_type>\w+) damage at initiative (?P<initiative>\d+)$").unwrap();
+        static ref weaknesses_and_immunities_regex: Regex = Regex::new(r"^(((weak to (?P<weaknesses>(\w+(, )?)+))|(immune to (?P<immunities>(\w+(, )?)+)))(; )?){1,2}$").unwrap();
+    }
+
+    let captures = line.captures(&army_group_regex, "an army group")?;
+    let mut army_group = ArmyGroup {
+        num_units: line.parse(&captures["num_units"])?,
+        team: current_army,
+        hit_points: line.parse(&captures["hit_points"])?,
+        attack_damage: line.parse(&captures["attack_damage"])?,
+        attack_type: captures["attack_type"].to_string(),
+        initiative: line.parse(&captures["initiative"])?,
+        weaknesses: Vec::new(),
+        immunities: Vec::new(),
+    };
+    if let Some(weaknesses_and_immunities_string) = captures.name("weaknesses_and_immunities") {
+        let captures = weaknesses_and_immunities_regex
+            .captures(weaknesses_and_immunities_string.as_str())
+            .ok_or_else(|| {
+                line.error_at(
+                    weaknesses_and_immunities_string.as_str(),
+                    "Expected weaknesses and/or immunities",
+                )
+            })?;
+        if let Some(weaknesses) = captures.name("weaknesses") {
+            for weakness in weaknesses.as_str().split(", ") {
+                army_group.weaknesses.push(weakness.to_string());
+            }
+        }
+        if let Some(immunities) = captures.name("immunities") {
+            for immunity in immunities.as_str().split(", ") {
+                army_group.immunities.push(immunity.to_string());
+            }
+        }
+    }
+    Ok(army_group)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_example() -> Vec<ArmyGroup> {
+        read_armies_from_input(aoc_common::lines(include_str!("../fixtures/example.txt"))).unwrap()
+    }
+
+    #[test]
+    fn example_armies() {
+        let army_groups = read_example();
+        assert_eq!(_format_armies(&army_groups), include_str!("../fixtures/example-armies.txt"));
+        assert_eq!(
+            army_groups[1],
+            ArmyGroup {
+                num_units: 989,
+                hit_points: 1274,
+                team: String::from("Immune System"),
+                attack_damage: 25,
+                attack_type: String::from("slashing"),
+                initiative: 3,
+                weaknesses: vec![String::from("bludgeoning"), String::from("slashing")],
+                immunities: vec![String::from("fire")],
+            }
+        );
+    }
+
+    #[test]
+    fn example_after_one_round() {
+        let mut army_groups = read_example();
+        assert!(fight_round(&mut army_groups));
+        assert_eq!(_format_armies(&army_groups), include_str!("../fixtures/example-after-1.txt"));
+    }
+
+    #[test]
+    fn example_fight() {
+        let army_groups = fight(read_example());
+        assert_eq!(_format_armies(&army_groups), include_str!("../fixtures/example-end.txt"));
+        assert_eq!(units_left(&army_groups), 5216);
+    }
+
+    #[test]
+    fn example_boosted_fight() {
+        let army_groups = fight(boost_immune_system(&read_example(), 1570));
+        assert_eq!(_format_armies(&army_groups), include_str!("../fixtures/example-boosted-end.txt"));
+        assert_eq!(units_left(&army_groups), 51);
+    }
+}
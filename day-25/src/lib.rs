@@ -0,0 +1,115 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+
+use aoc_common::{InputError, Line, Params, Solution};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+
+    type Input = Vec<Point>;
+    type Part1 = usize;
+    type Part2 = &'static str;
+
+    fn parse(input: &str, _params: &Params) -> Result<Vec<Point>, Box<dyn Error>> {
+        Ok(aoc_common::collect_results(aoc_common::lines(input).map(read_point_from_line))?)
+    }
+
+    fn part1(points: &Vec<Point>) -> usize {
+        let (constellations, _next_id) = points.iter().fold(
+            (HashMap::new(), 0),
+            |(mut constellations, mut next_id): (HashMap<i32, HashSet<&Point>>, i32), new_point| {
+                let linked_constellations: Vec<_> = constellations
+                    .iter()
+                    .filter(|(_id, points)| {
+                        points
+                            .iter()
+                            .filter(|pt| new_point.distance_to(pt) <= 3)
+                            .count()
+                            > 0
+                    })
+                    .collect();
+                if linked_constellations.is_empty() {
+                    let mut set = HashSet::new();
+                    set.insert(new_point);
+                    constellations.insert(next_id, set);
+                    next_id += 1;
+                } else {
+                    let (mut ids, joined_constellations) = linked_constellations.into_iter().fold(
+                        (Vec::new(), HashSet::new()),
+                        |(mut ids, mut all_points), (next_id, next_points)| {
+                            ids.push(next_id);
+                            all_points = all_points.union(next_points).copied().collect();
+                            all_points.insert(new_point);
+                            (ids, all_points)
+                        },
+                    );
+                    let first_id = *ids.pop().unwrap();
+                    let ids: Vec<i32> = ids.into_iter().copied().collect();
+                    ids.into_iter().for_each(|id| {
+                        constellations.remove(&id);
+                    });
+                    constellations.insert(first_id, joined_constellations);
+                }
+                (constellations, next_id)
+            },
+        );
+        constellations.len()
+    }
+
+    fn part2(_points: &Vec<Point>) -> &'static str {
+        "There is no part 2 for day 25"
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct Point {
+    i: i32,
+    j: i32,
+    k: i32,
+    l: i32,
+}
+
+impl Point {
+    fn distance_to(&self, other: &Point) -> i32 {
+        i32::abs(self.i - other.i)
+            + i32::abs(self.j - other.j)
+            + i32::abs(self.k - other.k)
+            + i32::abs(self.l - other.l)
+    }
+}
+
+fn read_point_from_line(line: Line) -> Result<Point, InputError> {
+    let values: Vec<_> = line.fields(",", 4)?.into_iter().map(|value| value.trim()).collect();
+    aoc_common::diagnostic!("{:?}", values);
+    Ok(Point {
+        i: line.parse::<i32>(values[0])?,
+        j: line.parse::<i32>(values[1])?,
+        k: line.parse::<i32>(values[2])?,
+        l: line.parse::<i32>(values[3])?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(input: &str) -> Vec<Point> {
+        Day25::parse(input, &Params::new()).unwrap()
+    }
+
+    #[test]
+    fn example_constellations() {
+        let points = read(include_str!("../fixtures/example-1.txt"));
+        assert_eq!(points[0].distance_to(&points[1]), 3);
+        assert_eq!(Day25::part1(&points), 2);
+        // A point between the two constellations joins them into one
+        let joined = read(&format!("{}6,0,0,0", include_str!("../fixtures/example-1.txt")));
+        assert_eq!(Day25::part1(&joined), 1);
+
+        assert_eq!(Day25::part1(&read(include_str!("../fixtures/example-2.txt"))), 4);
+        assert_eq!(Day25::part1(&read(include_str!("../fixtures/example-3.txt"))), 3);
+        assert_eq!(Day25::part1(&read(include_str!("../fixtures/example-4.txt"))), 8);
+    }
+}
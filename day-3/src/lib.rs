@@ -0,0 +1,132 @@
+extern crate itertools;
+extern crate regex;
+
+use self::itertools::Itertools;
+use self::regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{InputError, Line, ParseErrors, Params, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+
+    type Input = Vec<Claim>;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(input: &str, _params: &Params) -> Result<Vec<Claim>, Box<dyn std::error::Error>> {
+        Ok(read_claims(input)?)
+    }
+
+    fn part1(claims: &Vec<Claim>) -> usize {
+        double_claimed_area(claims)
+    }
+
+    fn part2(claims: &Vec<Claim>) -> i32 {
+        *claims_with_no_overlap(claims)
+            .first()
+            .expect("Every claim overlaps another claim")
+    }
+}
+
+pub fn read_claims(input: &str) -> Result<Vec<Claim>, ParseErrors> {
+    let rectangle_regex: Regex = Regex::new(r"#(\d+) @ (\d+),(\d+): (\d+)x(\d+)").unwrap();
+    aoc_common::collect_results(aoc_common::lines(input).map(|line| extract_claim(line, &rectangle_regex)))
+}
+
+pub fn double_claimed_area(claims: &[Claim]) -> usize {
+    let fabric_claims = claims.iter().fold(
+        HashMap::new(),
+        |mut fabric_claims: HashMap<(i32, i32), i32>, rect| {
+            for x in rect.left..rect.right {
+                for y in rect.top..rect.bottom {
+                    let num_claims = fabric_claims.get(&(x, y)).unwrap_or(&0) + 1;
+                    fabric_claims.insert((x, y), num_claims);
+                }
+            }
+            fabric_claims
+        },
+    );
+    fabric_claims
+        .iter()
+        .filter(|&(_point, &num_claims)| num_claims > 1)
+        .count()
+}
+
+pub fn claims_with_no_overlap(claims: &[Claim]) -> Vec<i32> {
+    let claims_involved_in_overlap: HashSet<i32> = claims
+        .iter()
+        // Get all possible combinations of different claims
+        .tuple_combinations()
+        // Keep tuples representing pairs of claims that overlap
+        .filter(|(c1, c2)| Claim::overlaps(c1, c2))
+        // Flatten to get the claim numbers of all claims involved in overlaps
+        .flat_map(|(c1, c2)| vec![c1.claim_number, c2.claim_number])
+        .collect();
+
+    claims
+        .iter()
+        .filter(|&claim| !claims_involved_in_overlap.contains(&claim.claim_number))
+        .map(|claim| claim.claim_number)
+        .collect()
+}
+
+#[derive(Debug)]
+pub struct Claim {
+    claim_number: i32,
+    left: i32,
+    right: i32,
+    top: i32,
+    bottom: i32,
+}
+
+impl Claim {
+    fn overlaps(c1: &Claim, c2: &Claim) -> bool {
+        c1.left < c2.right && c1.right > c2.left && c1.top < c2.bottom && c1.bottom > c2.top
+    }
+}
+
+impl Clone for Claim {
+    fn clone(&self) -> Claim {
+        Claim {
+            claim_number: self.claim_number,
+            left: self.left,
+            right: self.right,
+            top: self.top,
+            bottom: self.bottom,
+        }
+    }
+}
+
+fn extract_claim(line: Line, regex: &Regex) -> Result<Claim, InputError> {
+    let regex_groups = line.captures(regex, "a claim like \"#1 @ 1,3: 4x4\"")?;
+    let claim_number = line.parse::<i32>(&regex_groups[1])?;
+    let left = line.parse::<i32>(&regex_groups[2])?;
+    let top = line.parse::<i32>(&regex_groups[3])?;
+    let width = line.parse::<i32>(&regex_groups[4])?;
+    let height = line.parse::<i32>(&regex_groups[5])?;
+    Ok(Claim {
+        claim_number,
+        left,
+        right: left + width,
+        top,
+        bottom: top + height,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_claims() {
+        let claims = read_claims(include_str!("../fixtures/example.txt")).unwrap();
+        assert_eq!((claims[0].left, claims[0].right, claims[0].top, claims[0].bottom), (1, 5, 3, 7));
+        assert!(Claim::overlaps(&claims[0], &claims[1]));
+        assert!(!Claim::overlaps(&claims[0], &claims[2]));
+        assert_eq!(double_claimed_area(&claims), 4);
+        assert_eq!(claims_with_no_overlap(&claims), vec![3]);
+    }
+}
@@ -7,24 +7,82 @@ use chrono::Duration;
 use std::collections::HashMap;
 use itertools::Itertools;
 
-pub fn get_event_from_line(line: &str) -> Result<ShiftEvent, Box<std::error::Error>> {
+use aoc_common::{InputError, Line, Params, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+
+    type Input = Vec<Sleep>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str, _params: &Params) -> Result<Vec<Sleep>, Box<dyn std::error::Error>> {
+        // Parse the lines into shift events
+        let shift_events: Vec<ShiftEvent> =
+            aoc_common::collect_results(aoc_common::lines(input).map(get_event_from_line))?;
+
+        Ok(get_sleeps_from_shift_events(shift_events))
+    }
+
+    fn part1(sleeps: &Vec<Sleep>) -> i32 {
+        let guard_sleep_totals = get_guard_sleep_totals(sleeps);
+
+        let (most_asleep_guard, _minutes_slept) = guard_sleep_totals
+            .into_iter()
+            .max_by_key(|(_guard_number, duration)| duration.num_seconds())
+            .unwrap_or((0, chrono::Duration::seconds(0)));
+
+        let sleeps_by_lazy_guard: Vec<Sleep> =
+            get_sleeps_for_guard(sleeps.to_vec(), most_asleep_guard);
+
+        let time_slept_at_minute = get_time_slept_at_minute(&sleeps_by_lazy_guard);
+
+        let (most_slept_minute, _time_slept) = time_slept_at_minute
+            .into_iter()
+            .max_by_key(|(_minute, total)| *total)
+            .unwrap();
+        most_asleep_guard * most_slept_minute
+    }
+
+    fn part2(sleeps: &Vec<Sleep>) -> i32 {
+        let (most_asleep_guard, most_slept_minute, _time_slept) = get_guard_sleep_map(sleeps.to_vec())
+            .iter()
+            .map(|(guard, sleeps)| {
+                let (minute, time_slept) = get_time_slept_at_minute(sleeps)
+                    .into_iter()
+                    .max_by_key(|(_minute, total)| *total)
+                    .unwrap();
+                (*guard, minute, time_slept)
+            })
+            .max_by_key(|(_guard_number, _minute, time_slept)| *time_slept)
+            .unwrap();
+        most_asleep_guard * most_slept_minute
+    }
+}
+
+pub fn get_event_from_line(line: Line) -> Result<ShiftEvent, InputError> {
     lazy_static! {
         static ref event_regex: Regex =
             Regex::new(r"\[(\d{4})-(\d{2})-(\d{2}) (\d{2}):(\d{2})\] (.*)").unwrap();
         static ref guard_shift_regex: Regex = Regex::new(r"Guard #(\d+) begins shift").unwrap();
     }
 
-    let regex_groups = match event_regex.captures(line) {
-        None => Err("Could not match full event regex"),
-        Some(captures) => Ok(captures),
-    }?;
-    let year = regex_groups[1].parse::<i32>()?;
-    let month = regex_groups[2].parse::<u32>()?;
-    let day = regex_groups[3].parse::<u32>()?;
-    let hour = regex_groups[4].parse::<u32>()?;
-    let minute = regex_groups[5].parse::<u32>()?;
+    let regex_groups = line.captures(&event_regex, "an event like \"[1518-11-01 00:00] wakes up\"")?;
+    let year = line.parse::<i32>(&regex_groups[1])?;
+    let month = line.parse::<u32>(&regex_groups[2])?;
+    let day = line.parse::<u32>(&regex_groups[3])?;
+    let hour = line.parse::<u32>(&regex_groups[4])?;
+    let minute = line.parse::<u32>(&regex_groups[5])?;
 
-    let datetime = Local.ymd(year, month, day).and_hms(hour, minute, 0);
+    let datetime = match Local.with_ymd_and_hms(year, month, day, hour, minute, 0).single() {
+        None => Err(line.error_at(
+            regex_groups.get(0).unwrap().as_str(),
+            "Could not convert event timestamp to a local datetime",
+        )),
+        Some(datetime) => Ok(datetime),
+    }?;
 
     let event_string = &regex_groups[6];
 
@@ -32,9 +90,12 @@ pub fn get_event_from_line(line: &str) -> Result<ShiftEvent, Box<std::error::Err
         "falls asleep" => Ok(EventType::FallAsleep),
         "wakes up" => Ok(EventType::WakeUp),
         str => match guard_shift_regex.captures(str) {
-            None => Err("Could not match guard shift regex"),
+            None => Err(line.error_at(
+                str,
+                "Expected \"falls asleep\", \"wakes up\" or \"Guard #10 begins shift\"",
+            )),
             Some(captures) => {
-                let guard_number = captures[1].parse::<i32>()?;
+                let guard_number = line.parse::<i32>(&captures[1])?;
                 Ok(EventType::ShiftStart { guard_number })
             }
         },
@@ -69,16 +130,18 @@ pub fn get_sleeps_from_shift_events(mut shift_events: Vec<ShiftEvent>) -> Vec<Sl
                     ..state
                 },
                 EventType::WakeUp => {
-                    if state.guard_number.is_some() && state.asleep_since.is_some() {
+                    if let (Some(guard_number), Some(asleep_since)) =
+                        (state.guard_number, state.asleep_since)
+                    {
                         sleeps.push(Sleep {
-                            guard_number: state.guard_number.unwrap(),
+                            guard_number,
                             asleep_between: (
-                                state.asleep_since.unwrap(),
+                                asleep_since,
                                 guard_shift_event.datetime,
                             ),
                         })
                     } else {
-                        eprintln!("Weird state found {:?}, {:?}", state, guard_shift_event);
+                        aoc_common::diagnostic!("Weird state found {:?}, {:?}", state, guard_shift_event);
                     }
                     GuardState {
                         asleep_since: None,
@@ -110,6 +173,13 @@ pub fn get_guard_sleep_totals(sleeps: &[Sleep]) -> HashMap<i32, Duration> {
         })
 }
 
+pub fn get_sleeps_for_guard(sleeps: Vec<Sleep>, guard_number: i32) -> Vec<Sleep> {
+    sleeps
+        .into_iter()
+        .filter(|sleep| sleep.guard_number == guard_number)
+        .collect()
+}
+
 pub fn get_guard_sleep_map(sleeps: Vec<Sleep>) -> HashMap<i32, Vec<Sleep>> {
     sleeps
         .into_iter()
@@ -154,7 +224,7 @@ impl Ord for ShiftEvent {
 
 impl PartialOrd for ShiftEvent {
     fn partial_cmp(&self, other: &ShiftEvent) -> Option<Ordering> {
-        self.datetime.partial_cmp(&other.datetime)
+        Some(self.cmp(other))
     }
 }
 
@@ -166,7 +236,7 @@ impl PartialEq for ShiftEvent {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Sleep {
     guard_number: i32,
     asleep_between: (DateTime<Local>, DateTime<Local>),
@@ -177,3 +247,32 @@ struct GuardState {
     guard_number: Option<i32>,
     asleep_since: Option<DateTime<Local>>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_example() -> Vec<Sleep> {
+        Day4::parse(include_str!("../fixtures/example.txt"), &Params::new()).unwrap()
+    }
+
+    #[test]
+    fn example_sleep_totals() {
+        let sleeps = read_example();
+        assert_eq!(sleeps.len(), 6);
+        let totals = get_guard_sleep_totals(&sleeps);
+        assert_eq!(totals[&10].num_minutes(), 50);
+        assert_eq!(totals[&99].num_minutes(), 30);
+        assert_eq!(Day4::part1(&sleeps), 10 * 24);
+    }
+
+    #[test]
+    fn example_minutes_asleep() {
+        let sleeps_by_guard = get_guard_sleep_map(read_example());
+        let guard_10 = get_time_slept_at_minute(&sleeps_by_guard[&10]);
+        assert_eq!((guard_10[&24], guard_10[&5], guard_10[&55]), (2, 1, 0));
+        let guard_99 = get_time_slept_at_minute(&sleeps_by_guard[&99]);
+        assert_eq!(guard_99[&45], 3);
+        assert_eq!(Day4::part2(&read_example()), 99 * 45);
+    }
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use aoc_common::{Params, Solution};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str, _params: &Params) -> Result<String, Box<dyn Error>> {
+        Ok(input.trim_end().to_string())
+    }
+
+    fn part1(polymer: &String) -> usize {
+        react(polymer).len()
+    }
+
+    fn part2(polymer: &String) -> usize {
+        let results = react_without_each_letter(polymer);
+        let result = results
+            .iter()
+            .min_by_key(|(_letter, string)| string.len())
+            .unwrap();
+        result.1.len()
+    }
+}
+
+fn react(polymer: &str) -> String {
+    polymer.chars().fold(
+        String::from(""),
+        |mut confirmed_string: String, next_char| {
+            match confirmed_string.pop() {
+                Some(prev_char) => {
+                    if prev_char != next_char
+                        && prev_char.to_lowercase().to_string()
+                            == next_char.to_lowercase().to_string()
+                    {
+                        // REACT
+                        confirmed_string
+                    } else {
+                        confirmed_string + &prev_char.to_string() + &next_char.to_string()
+                    }
+                }
+                None => confirmed_string + &next_char.to_string(),
+            }
+        },
+    )
+}
+
+fn react_without_each_letter(polymer: &str) -> HashMap<char, String> {
+    const ALPHABET: [char; 26] = [
+        'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r',
+        's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
+    ];
+
+    polymer.chars().fold(
+        ALPHABET.iter().map(|&c| (c, String::from(""))).collect(),
+        |current_strings: HashMap<char, String>, next_char| {
+            current_strings
+                .into_iter()
+                .map(|(letter, mut confirmed_string)| {
+                    // Ignore values of the specified letter
+                    if next_char.to_lowercase().to_string() == letter.to_string() {
+                        return (letter, confirmed_string);
+                    }
+                    match confirmed_string.pop() {
+                        Some(prev_char) => {
+                            if prev_char != next_char
+                                && prev_char.to_lowercase().to_string()
+                                    == next_char.to_lowercase().to_string()
+                            {
+                                // REACT
+                                (letter, confirmed_string)
+                            } else {
+                                (
+                                    letter,
+                                    confirmed_string
+                                        + &prev_char.to_string()
+                                        + &next_char.to_string(),
+                                )
+                            }
+                        }
+                        None => (letter, confirmed_string + &next_char.to_string()),
+                    }
+                })
+                .collect::<HashMap<char, String>>()
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_reactions() {
+        let polymer = Day5::parse(include_str!("../fixtures/example.txt"), &Params::new()).unwrap();
+        assert_eq!(react(&polymer), "dabCBAcaDA");
+        let results = react_without_each_letter(&polymer);
+        assert_eq!(results[&'a'], "dbCBcD");
+        assert_eq!(results[&'b'], "daCAcaDA");
+        assert_eq!(results[&'c'], "daDA");
+        assert_eq!(results[&'d'], "abCBAc");
+    }
+}
@@ -0,0 +1,243 @@
+extern crate regex;
+#[macro_use]
+extern crate lazy_static;
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+
+use regex::Regex;
+
+use aoc_common::{InputError, Line, Param, Params, Solution};
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+pub struct Coordinates {
+    points: HashMap<usize, Point>,
+    /// Points in the part 2 region must have a total distance to every coordinate below this
+    max_total_distance: i32,
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "max_total_distance",
+        description: "the total distance bounding the part 2 region (default 10000)",
+    }];
+
+    type Input = Coordinates;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str, params: &Params) -> Result<Coordinates, Box<dyn Error>> {
+        let points: HashMap<usize, Point> =
+            aoc_common::collect_results(aoc_common::lines(input).map(read_point_from_line))?
+                .into_iter()
+                .enumerate()
+                .collect();
+        if points.is_empty() {
+            return Err("The input has no coordinates in it".into());
+        }
+        Ok(Coordinates {
+            points,
+            max_total_distance: params.get_or("max_total_distance", 10000)?,
+        })
+    }
+
+    fn part1(coordinates: &Coordinates) -> i32 {
+        let points = &coordinates.points;
+        let bounds = get_bounds(points);
+
+        let mut infinite_points = HashSet::new();
+        //Calculate for x edges
+        for x in bounds.0.x..bounds.1.x {
+            let target_point = Point { x, y: bounds.0.y };
+            if let Some(id) = closest_point_to_point(&target_point, points) {
+                infinite_points.insert(id);
+            }
+            let target_point = Point { x, y: bounds.1.y };
+            if let Some(id) = closest_point_to_point(&target_point, points) {
+                infinite_points.insert(id);
+            }
+        }
+
+        //Calculate for y edges
+        for y in bounds.0.y..bounds.1.y {
+            let target_point = Point { x: bounds.0.x, y };
+            if let Some(id) = closest_point_to_point(&target_point, points) {
+                infinite_points.insert(id);
+            }
+            let target_point = Point { x: bounds.1.x, y };
+            if let Some(id) = closest_point_to_point(&target_point, points) {
+                infinite_points.insert(id);
+            }
+        }
+
+        //Calculate total areas for finite areas, then find max
+        points
+            .iter()
+            .filter(|(&id, _)| !infinite_points.contains(&id))
+            .map(|point| total_area(point, points))
+            .max()
+            .expect("No finite areas found")
+    }
+
+    fn part2(coordinates: &Coordinates) -> i32 {
+        let points = &coordinates.points;
+        let bounds = get_bounds(points);
+
+        let mut region_size = 0;
+        // Calculate region size
+        for x in bounds.0.x..bounds.1.x {
+            for y in bounds.0.y..bounds.1.y {
+                let current_point = Point { x, y };
+                let sum_of_distances = points.iter().fold(0, |sum_of_distances, (_id, point)| {
+                    sum_of_distances + manhattan_distance(point, &current_point)
+                });
+                if sum_of_distances < coordinates.max_total_distance {
+                    region_size += 1;
+                }
+            }
+        }
+        region_size
+    }
+}
+
+fn get_bounds(points: &HashMap<usize, Point>) -> (Point, Point) {
+    points.iter().fold(
+        (
+            Point {
+                x: i32::MAX,
+                y: i32::MAX,
+            },
+            Point {
+                x: i32::MIN,
+                y: i32::MIN,
+            },
+        ),
+        |bounds, (_id, point)| {
+            let min_point = Point {
+                x: i32::min(bounds.0.x, point.x),
+                y: i32::min(bounds.0.y, point.y),
+            };
+            let max_point = Point {
+                x: i32::max(bounds.1.x, point.x),
+                y: i32::max(bounds.1.y, point.y),
+            };
+            (min_point, max_point)
+        },
+    )
+}
+
+fn read_point_from_line(line: Line) -> Result<Point, InputError> {
+    lazy_static! {
+        static ref point_regex: Regex = Regex::new(r"(\d+), (\d+)").unwrap();
+    }
+    let captures = line.captures(&point_regex, "a point like \"1, 6\"")?;
+    let x = line.parse::<i32>(&captures[1])?;
+    let y = line.parse::<i32>(&captures[2])?;
+    Ok(Point { x, y })
+}
+
+fn closest_point_to_point<'a>(
+    target_point: &Point,
+    points: &'a HashMap<usize, Point>,
+) -> Option<&'a usize> {
+    let (_min, pts_with_min_manhattan_distance) = points.iter().fold(
+        (i32::MAX, Vec::new()),
+        |(current_min, mut points), (id, next_point)| {
+            let distance = manhattan_distance(next_point, target_point);
+            match distance {
+                distance if distance < current_min => (distance, vec![id]),
+                _distance if distance == current_min => {
+                    points.push(id);
+                    (current_min, points)
+                }
+                _distance => (current_min, points),
+            }
+        },
+    );
+    match pts_with_min_manhattan_distance.len() {
+        0 => None,
+        1 => Some(*pts_with_min_manhattan_distance.first().unwrap()),
+        _ => None,
+    }
+}
+
+fn manhattan_distance(p1: &Point, p2: &Point) -> i32 {
+    i32::abs(p1.x - p2.x) + i32::abs(p1.y - p2.y)
+}
+
+fn total_area(point_and_id: (&usize, &Point), all_points: &HashMap<usize, Point>) -> i32 {
+    let (id, target_point) = point_and_id;
+    let mut count = 0;
+    let mut points_checked = HashSet::new();
+    let mut points_to_check: Vec<Point> = Vec::new();
+    points_to_check.push(Point{ x: target_point.x, y: target_point.y});
+
+    while let Some(current_point) = points_to_check.pop() {
+        
+        if closest_point_to_point(&current_point, all_points) == Some(id) {
+            count += 1;
+
+            get_adjacent_points(&current_point).into_iter().for_each(|adj_point| {
+                if !points_checked.contains(&adj_point) && !points_to_check.contains(&adj_point) {
+                    points_to_check.push(adj_point);
+                }
+            });
+        }
+        points_checked.insert(current_point);
+    }
+    count
+}
+
+fn get_adjacent_points(point: &Point) -> Vec<Point> {
+    vec![
+        Point {
+            x: point.x - 1,
+            ..*point
+        },
+        Point {
+            x: point.x + 1,
+            ..*point
+        },
+        Point {
+            y: point.y - 1,
+            ..*point
+        },
+        Point {
+            y: point.y + 1,
+            ..*point
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_example() -> Coordinates {
+        let mut params = Params::new();
+        params.insert("max_total_distance", "32");
+        Day6::parse(include_str!("../fixtures/example.txt"), &params).unwrap()
+    }
+
+    #[test]
+    fn example_areas() {
+        let coordinates = read_example();
+        let points = &coordinates.points;
+        assert_eq!(get_bounds(points), (Point { x: 1, y: 1 }, Point { x: 8, y: 9 }));
+        // D and E are the only finite areas
+        assert_eq!(total_area((&3, &points[&3]), points), 9);
+        assert_eq!(total_area((&4, &points[&4]), points), 17);
+        // Equally far from A and D
+        assert_eq!(closest_point_to_point(&Point { x: 0, y: 4 }, points), None);
+        assert_eq!(Day6::part1(&coordinates), 17);
+        assert_eq!(Day6::part2(&coordinates), 16);
+    }
+}
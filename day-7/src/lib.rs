@@ -0,0 +1,217 @@
+extern crate regex;
+#[macro_use]
+extern crate lazy_static;
+
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::iter::FromIterator;
+
+use aoc_common::{InputError, Line, Param, Params, Solution};
+
+pub struct Instructions {
+    steps: HashMap<String, HashSet<String>>,
+    workers: usize,
+    base_duration: i32,
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "workers",
+            description: "the number of workers (default 5)",
+        },
+        Param {
+            name: "base_duration",
+            description: "the seconds every step takes before its letter is added (default 60)",
+        },
+    ];
+
+    type Input = Instructions;
+    type Part1 = String;
+    type Part2 = i32;
+
+    fn parse(input: &str, params: &Params) -> Result<Instructions, Box<dyn Error>> {
+        let workers: usize = params.get_or("workers", 5)?;
+        if workers < 1 {
+            return Err("Must have more than zero workers to complete instructions".into());
+        }
+        let requirements =
+            aoc_common::collect_results(aoc_common::lines(input).map(get_requirement_from_line))?;
+        let steps = requirements.into_iter().fold(
+            HashMap::new(),
+            |mut steps: HashMap<String, HashSet<String>>, requirement: Requirement| {
+                // Create new set for both steps if needed
+                steps
+                    .entry(requirement.target_identifier.clone())
+                    .or_default();
+                steps
+                    .entry(requirement.required_identifier.clone())
+                    .or_default();
+
+                //Add requirement
+                steps
+                    .get_mut(&requirement.target_identifier)
+                    .unwrap()
+                    .insert(requirement.required_identifier);
+                steps
+            },
+        );
+        for step in &steps {
+            aoc_common::diagnostic!("{:?}", step);
+        }
+        Ok(Instructions {
+            steps,
+            workers,
+            base_duration: params.get_or("base_duration", 60)?,
+        })
+    }
+
+    fn part1(instructions: &Instructions) -> String {
+        let mut steps = instructions.steps.clone();
+        let mut completed_steps = Vec::new();
+        while !steps.is_empty() {
+            match get_next_step(&steps, &completed_steps) {
+                Some(step) => {
+                    steps.remove(&step);
+                    completed_steps.push(step);
+                }
+                None => panic!("Could not complete instructions - perhaps there is a cycle"),
+            }
+        }
+        completed_steps.join("")
+    }
+
+    fn part2(instructions: &Instructions) -> i32 {
+        let mut to_do_steps = instructions.steps.clone();
+        let mut completed_steps: Vec<String> = Vec::new();
+        let mut working_steps: Vec<StepInProgress> = Vec::new();
+        let mut seconds_passed = 0;
+        while !to_do_steps.is_empty() {
+            // Assign free workers
+            let mut next_step = get_next_step(&to_do_steps, &completed_steps);
+            while working_steps.len() < instructions.workers && next_step.is_some() {
+                let step = next_step.unwrap();
+                working_steps.push(StepInProgress {
+                    step_identifier: step.to_string(),
+                    time_til_complete: get_time_til_complete(
+                        &step.to_lowercase(),
+                        instructions.base_duration,
+                    ),
+                });
+                to_do_steps.remove(&step);
+                next_step = get_next_step(&to_do_steps, &completed_steps);
+            }
+
+            // Complete next step
+            let next_completed_index = working_steps
+                .iter()
+                .enumerate()
+                .min_by_key(|(_index, step)| step.time_til_complete).map(|(index, _completed_step)| index);
+            if let Some(index) = next_completed_index {
+                // Move step to completed
+                let completed_step = working_steps.remove(index);
+                completed_steps.push(completed_step.step_identifier.to_string());
+                // Reduce time to complete on other steps
+                seconds_passed += completed_step.time_til_complete;
+                for step in &mut working_steps {
+                    step.time_til_complete -= completed_step.time_til_complete;
+                }
+            }
+        }
+        seconds_passed
+    }
+}
+
+#[derive(Debug)]
+struct Requirement {
+    required_identifier: String,
+    target_identifier: String,
+}
+
+#[derive(Debug)]
+struct StepInProgress {
+    step_identifier: String,
+    time_til_complete: i32,
+}
+
+fn get_requirement_from_line(line: Line) -> Result<Requirement, InputError> {
+    lazy_static! {
+        static ref requirement_regex: Regex = Regex::new(
+            r"Step ([[:alpha:]]+) must be finished before step ([[:alpha:]]+) can begin."
+        )
+        .unwrap();
+    }
+    let captures = line.captures(
+        &requirement_regex,
+        "a requirement like \"Step C must be finished before step A can begin.\"",
+    )?;
+    let required_identifier = String::from(&captures[1]);
+    let target_identifier = String::from(&captures[2]);
+    Ok(Requirement {
+        required_identifier,
+        target_identifier,
+    })
+}
+
+fn get_next_step(
+    steps: &HashMap<String, HashSet<String>>,
+    completed_steps: &[String],
+) -> Option<String> {
+    let completed_steps_set: HashSet<&String> = HashSet::from_iter(completed_steps.iter());
+    let mut valid_steps: Vec<&String> = steps
+        .iter()
+        .filter(|(_step_id, requirements)| {
+            let requirement_refs_set = HashSet::from_iter(requirements.iter());
+            requirement_refs_set.is_subset(&completed_steps_set)
+        })
+        .map(|(step_id, _requirements)| step_id)
+        .collect();
+    valid_steps.sort();
+    valid_steps.first().map(|step| step.to_string())
+}
+
+fn get_time_til_complete(step_identifier: &str, base_duration: i32) -> i32 {
+    lazy_static! {
+        static ref alphabet: String = String::from("abcdefghijklmnopqrstuvwxyz");
+    }
+    let extra_time: i32 = step_identifier
+        .to_lowercase()
+        .chars()
+        .map(|c| alphabet.find(c).unwrap() as i32)
+        .sum();
+    // Add one more than the base duration to make A = 61, B = 62...
+    base_duration + 1 + extra_time
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_example() -> Instructions {
+        let mut params = Params::new();
+        params.insert("workers", "2");
+        params.insert("base_duration", "0");
+        Day7::parse(include_str!("../fixtures/example.txt"), &params).unwrap()
+    }
+
+    #[test]
+    fn example_step_order() {
+        let instructions = read_example();
+        assert_eq!(instructions.steps.len(), 6);
+        assert_eq!(get_next_step(&instructions.steps, &[]), Some(String::from("C")));
+        let done = [String::from("C")];
+        assert_eq!(get_next_step(&instructions.steps, &done), Some(String::from("A")));
+        assert_eq!(Day7::part1(&instructions), "CABDFE");
+    }
+
+    #[test]
+    fn example_with_workers() {
+        assert_eq!(get_time_til_complete("A", 60), 61);
+        assert_eq!(get_time_til_complete("Z", 0), 26);
+        assert_eq!(Day7::part2(&read_example()), 15);
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use aoc_common::{InputError, Line, Params, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+
+    type Input = HashMap<usize, Node>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str, _params: &Params) -> Result<HashMap<usize, Node>, Box<dyn Error>> {
+        let line_count = input.lines().count();
+        let number_count = input.split_whitespace().count();
+        let mut numbers = aoc_common::lines(input)
+            .flat_map(|line| line.text.split_whitespace().map(move |field| (line, field)));
+
+        let mut next_id = 0;
+        let mut expected_numbers = vec![ExpectedType::Header(next_id)];
+        let mut nodes: HashMap<usize, Node> = HashMap::new();
+
+        while let Some(expected) = expected_numbers.pop() {
+            match expected {
+                ExpectedType::Header(this_node) => {
+                    // Read header
+                    let num_children =
+                        fetch_count(&mut numbers, line_count, number_count, "the number of children")?;
+                    let num_metadata_entries =
+                        fetch_count(&mut numbers, line_count, number_count, "the number of metadata entries")?;
+
+                    // Push the metadata onto stack first, as we will read it last
+                    expected_numbers.append(&mut vec![
+                        ExpectedType::MetadataEntry(this_node);
+                        num_metadata_entries
+                    ]);
+
+                    // Get the ids for the children for this node and increment the id tracker
+                    let children_ids = (next_id + 1..next_id + 1 + num_children)
+                        .collect::<Vec<usize>>();
+                    next_id += num_children;
+
+                    // Reverse ids for stack (to keep child indexes consistent)
+                    let mut header_ids = children_ids.clone();
+                    header_ids.reverse();
+
+                    // Push expected headers onto stack
+                    let mut new_node_headers = header_ids
+                        .iter()
+                        .map(|&id| ExpectedType::Header(id))
+                        .collect();
+                    expected_numbers.append(&mut new_node_headers);
+
+                    //Add the node to the hashmap
+                    nodes.insert(
+                        this_node,
+                        Node {
+                            children: children_ids,
+                            metadata: Vec::new(),
+                        },
+                    );
+                }
+                ExpectedType::MetadataEntry(parent) => {
+                    let metadata = fetch_next_number(&mut numbers, line_count, "a metadata entry")?;
+                    nodes.get_mut(&parent).unwrap().metadata.push(metadata);
+                }
+            }
+        }
+        Ok(nodes)
+    }
+
+    fn part1(nodes: &HashMap<usize, Node>) -> i32 {
+        nodes.values().flat_map(|node| node.metadata.iter()).sum()
+    }
+
+    fn part2(nodes: &HashMap<usize, Node>) -> i32 {
+        value_of_node(&0, nodes)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum ExpectedType {
+    Header(usize),
+    MetadataEntry(usize),
+}
+
+#[derive(Debug)]
+pub struct Node {
+    children: Vec<usize>,
+    metadata: Vec<i32>,
+}
+
+fn fetch_next_number<'a, I>(numbers: &mut I, line_count: usize, expected: &str) -> Result<i32, InputError>
+where
+    I: Iterator<Item = (Line<'a>, &'a str)>,
+{
+    match numbers.next() {
+        Some((line, field)) => line.parse::<i32>(field),
+        None => Err(InputError::end_of_input(line_count, expected)),
+    }
+}
+
+/// Reads a number of children or metadata entries, which can't be more than there are numbers
+/// in the whole input.
+fn fetch_count<'a, I>(numbers: &mut I, line_count: usize, number_count: usize, expected: &str) -> Result<usize, InputError>
+where
+    I: Iterator<Item = (Line<'a>, &'a str)>,
+{
+    match numbers.next() {
+        Some((line, field)) => {
+            let count = line.parse::<usize>(field)?;
+            if count > number_count {
+                return Err(line.error_at(field, format!("The input only has {} numbers in it", number_count)));
+            }
+            Ok(count)
+        }
+        None => Err(InputError::end_of_input(line_count, expected)),
+    }
+}
+
+fn value_of_node(node_id: &usize, nodes: &HashMap<usize, Node>) -> i32 {
+    match nodes.get(node_id) {
+        Some(node) => {
+            if !node.children.is_empty() {
+                node.metadata
+                    .iter()
+                    .filter_map(|&index| node.children.get((index - 1) as usize))
+                    .map(|id| value_of_node(id, nodes))
+                    .sum()
+            } else {
+                node.metadata.iter().sum()
+            }
+        }
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+
+    #[test]
+    fn parses_from_a_reader_or_a_literal() {
+        let example = include_str!("../fixtures/example.txt");
+        let from_reader = Day8::parse_reader(BufReader::new(example.as_bytes()), &Params::new()).unwrap();
+        assert_eq!(Day8::part1(&from_reader), 138);
+        let from_literal = Day8::parse("1 1 0 1 99 2", &Params::new()).unwrap();
+        assert_eq!(Day8::part1(&from_literal), 101);
+        assert_eq!(Day8::part2(&from_literal), 0);
+    }
+
+    #[test]
+    fn rejects_impossible_counts() {
+        let error = Day8::parse("0 -1", &Params::new()).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 3: Unable to parse \"-1\": invalid digit found in string\n1 | 0 -1\n  |   ^^"
+        );
+        let error = Day8::parse("0 99999999999", &Params::new()).err().unwrap();
+        assert!(error.to_string().starts_with("line 1, column 3: The input only has 2 numbers in it"));
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+
+use aoc_common::{Param, Params, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+    const TAKES_INPUT: bool = false;
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "players",
+            description: "the number of players",
+        },
+        Param {
+            name: "last_marble",
+            description: "the value of the final marble",
+        },
+    ];
+
+    type Input = GameParameters;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(_input: &str, params: &Params) -> Result<GameParameters, Box<dyn Error>> {
+        Ok(GameParameters {
+            num_players: params.get("players")?,
+            final_marble: params.get("last_marble")?,
+        })
+    }
+
+    fn part1(game_params: &GameParameters) -> u64 {
+        winning_score(game_params)
+    }
+
+    fn part2(game_params: &GameParameters) -> u64 {
+        winning_score(&GameParameters {
+            final_marble: game_params.final_marble * 100,
+            ..*game_params
+        })
+    }
+}
+
+fn winning_score(game_params: &GameParameters) -> u64 {
+    let mut marble_circle = VecDeque::new();
+    marble_circle.push_front(0);
+    let mut scores: HashMap<u32, u64> = HashMap::new();
+    for marble in 1..game_params.final_marble + 1 {
+        if marble % 23 == 0 {
+            //Special scoring
+            rotate(&mut marble_circle, 7, Direction::CounterClockwise);
+            let score_increase = marble + marble_circle.pop_front().unwrap_or(0);
+            *scores.entry(marble % game_params.num_players).or_insert(0) += u64::from(score_increase);
+        } else {
+            rotate(&mut marble_circle, 2, Direction::Clockwise);
+            marble_circle.push_front(marble);
+        }
+    }
+    scores.values().cloned().max().unwrap_or(0)
+}
+
+pub struct GameParameters {
+    num_players: u32,
+    final_marble: u32,
+}
+
+enum Direction {
+    Clockwise,
+    CounterClockwise,
+}
+
+fn rotate<T>(circle: &mut VecDeque<T>, rotations: u32, direction: Direction) {
+    for _ in 0..rotations {
+        let element = match &direction {
+            Direction::Clockwise => circle.pop_front(),
+            Direction::CounterClockwise => circle.pop_back(),
+        };
+        match (element, &direction) {
+            (Some(element), Direction::Clockwise) => circle.push_back(element),
+            (Some(element), Direction::CounterClockwise) => circle.push_front(element),
+            _ => ()
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use crate::{Instruction, Op, Program};
+
+/// The most registers a machine can have. A power of two, so that masking a register index
+/// keeps it in range and lets the compiler drop the bounds checks when executing.
+pub const MAX_REGISTERS: usize = 8;
+
+const REGISTER_MASK: usize = MAX_REGISTERS - 1;
+
+/// A register index that has already been checked against the program's register count.
+type Register = u8;
+
+/// The value that an instruction computes, with each argument already known to be either a
+/// register or a value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Operation {
+    Addr(Register, Register),
+    Addi(Register, usize),
+    Mulr(Register, Register),
+    Muli(Register, usize),
+    Banr(Register, Register),
+    Bani(Register, usize),
+    Borr(Register, Register),
+    Bori(Register, usize),
+    Setr(Register),
+    Seti(usize),
+    Gtir(usize, Register),
+    Gtri(Register, usize),
+    Gtrr(Register, Register),
+    Eqir(usize, Register),
+    Eqri(Register, usize),
+    Eqrr(Register, Register),
+}
+
+impl Operation {
+    fn decode(instruction: &Instruction) -> Operation {
+        let Instruction { op, a, b, .. } = *instruction;
+        let (ra, rb) = (a as Register, b as Register);
+        match op {
+            Op::Addr => Operation::Addr(ra, rb),
+            Op::Addi => Operation::Addi(ra, b),
+            Op::Mulr => Operation::Mulr(ra, rb),
+            Op::Muli => Operation::Muli(ra, b),
+            Op::Banr => Operation::Banr(ra, rb),
+            Op::Bani => Operation::Bani(ra, b),
+            Op::Borr => Operation::Borr(ra, rb),
+            Op::Bori => Operation::Bori(ra, b),
+            Op::Setr => Operation::Setr(ra),
+            Op::Seti => Operation::Seti(a),
+            Op::Gtir => Operation::Gtir(a, rb),
+            Op::Gtri => Operation::Gtri(ra, b),
+            Op::Gtrr => Operation::Gtrr(ra, rb),
+            Op::Eqir => Operation::Eqir(a, rb),
+            Op::Eqri => Operation::Eqri(ra, b),
+            Op::Eqrr => Operation::Eqrr(ra, rb),
+        }
+    }
+
+    #[inline(always)]
+    fn evaluate(self, registers: &[usize; MAX_REGISTERS]) -> usize {
+        let r = |register: Register| registers[usize::from(register) & REGISTER_MASK];
+        match self {
+            Operation::Addr(a, b) => r(a) + r(b),
+            Operation::Addi(a, b) => r(a) + b,
+            Operation::Mulr(a, b) => r(a) * r(b),
+            Operation::Muli(a, b) => r(a) * b,
+            Operation::Banr(a, b) => r(a) & r(b),
+            Operation::Bani(a, b) => r(a) & b,
+            Operation::Borr(a, b) => r(a) | r(b),
+            Operation::Bori(a, b) => r(a) | b,
+            Operation::Setr(a) => r(a),
+            Operation::Seti(a) => a,
+            Operation::Gtir(a, b) => (a > r(b)) as usize,
+            Operation::Gtri(a, b) => (r(a) > b) as usize,
+            Operation::Gtrr(a, b) => (r(a) > r(b)) as usize,
+            Operation::Eqir(a, b) => (a == r(b)) as usize,
+            Operation::Eqri(a, b) => (r(a) == b) as usize,
+            Operation::Eqrr(a, b) => (r(a) == r(b)) as usize,
+        }
+    }
+}
+
+/// An instruction decoded ahead of time. The bound instruction pointer register always holds
+/// the instruction's own address while it runs, so reads of it are decoded as that value and
+/// writes to it as jumps, leaving the register itself off the path from one instruction to
+/// the next.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Decoded {
+    operation: Operation,
+    c: Register,
+    jumps: bool,
+}
+
+impl Decoded {
+    fn decode(instruction: &Instruction, address: usize, ip_register: Option<usize>) -> Decoded {
+        let instruction = match ip_register {
+            Some(ip_register) => inline_ip_register(*instruction, address, ip_register),
+            None => *instruction,
+        };
+        Decoded {
+            operation: Operation::decode(&instruction),
+            c: instruction.c as Register,
+            jumps: Some(instruction.c) == ip_register,
+        }
+    }
+}
+
+/// Rewrites an instruction at `address` so that it no longer reads the instruction pointer
+/// register, using the immediate form of the op or working the value out ahead of time.
+pub(crate) fn inline_ip_register(instruction: Instruction, address: usize, ip_register: usize) -> Instruction {
+    let Instruction { op, a, b, c } = instruction;
+    let a_is_ip = op.a_is_register() && a == ip_register;
+    let b_is_ip = op.b_is_register() && b == ip_register;
+    let reads_other_register = (op.a_is_register() && !a_is_ip) || (op.b_is_register() && !b_is_ip);
+    if !a_is_ip && !b_is_ip {
+        return instruction;
+    }
+    if !reads_other_register {
+        let mut registers = [0; MAX_REGISTERS];
+        registers[ip_register] = address;
+        instruction.execute(&mut registers);
+        return Instruction::new(Op::Seti, registers[c], 0, c);
+    }
+    // Exactly one argument is the instruction pointer, and the other is another register
+    let (other, swapped) = if a_is_ip { (b, true) } else { (a, false) };
+    let op = match op {
+        Op::Addr => Op::Addi,
+        Op::Mulr => Op::Muli,
+        Op::Banr => Op::Bani,
+        Op::Borr => Op::Bori,
+        Op::Gtrr if swapped => return Instruction::new(Op::Gtir, address, other, c),
+        Op::Gtrr => Op::Gtri,
+        Op::Eqrr => Op::Eqri,
+        _ => return instruction,
+    };
+    Instruction::new(op, other, address, c)
+}
+
+/// A program decoded for the machine to run, so that nothing needs to be checked or
+/// allocated per instruction.
+#[derive(Clone, Debug)]
+pub struct Compiled {
+    code: Vec<Decoded>,
+    pub register_count: usize,
+    pub ip_register: Option<usize>,
+}
+
+impl Compiled {
+    /// Panics if any instruction, or the instruction pointer binding, refers to a register
+    /// outside of the first `register_count`, or if there are more than `MAX_REGISTERS`.
+    pub fn new(instructions: &[Instruction], register_count: usize, ip_register: Option<usize>) -> Compiled {
+        assert!(
+            register_count <= MAX_REGISTERS,
+            "A machine can have at most {} registers",
+            MAX_REGISTERS
+        );
+        assert!(
+            ip_register.is_none_or(|register| register < register_count),
+            "The instruction pointer must be bound to one of the {} registers",
+            register_count
+        );
+        let code = instructions
+            .iter()
+            .enumerate()
+            .map(|(address, instruction)| {
+                assert!(
+                    instruction.fits_registers(register_count),
+                    "{} uses a register outside of the {} registers",
+                    instruction,
+                    register_count
+                );
+                Decoded::decode(instruction, address, ip_register)
+            })
+            .collect();
+        Compiled {
+            code,
+            register_count,
+            ip_register,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.code.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.code.is_empty()
+    }
+}
+
+impl Program {
+    pub fn compile(&self) -> Compiled {
+        Compiled::new(&self.instructions, self.register_count, self.ip_register)
+    }
+}
+
+/// Runs the instruction at `ip` in place, returning the next instruction pointer.
+#[inline(always)]
+pub(crate) fn execute_at(program: &Compiled, ip: usize, registers: &mut [usize; MAX_REGISTERS]) -> usize {
+    let instruction = program.code[ip];
+    let value = instruction.operation.evaluate(registers);
+    let next_ip = if instruction.jumps {
+        value + 1
+    } else {
+        registers[usize::from(instruction.c) & REGISTER_MASK] = value;
+        ip + 1
+    };
+    if let Some(ip_register) = program.ip_register {
+        // Leave the register as it would be after writing the pointer to it and back again
+        registers[ip_register & REGISTER_MASK] = next_ip - 1;
+    }
+    next_ip
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Machine;
+
+    /// Runs the program one plain instruction at a time, writing the pointer to its register
+    /// and back around every instruction.
+    fn reference_run(program: &Program, limit: usize) -> (usize, Vec<usize>) {
+        let ip_register = program.ip_register.unwrap();
+        let mut registers = vec![0; program.register_count];
+        let mut ip = 0;
+        for _ in 0..limit {
+            if ip >= program.instructions.len() {
+                break;
+            }
+            registers[ip_register] = ip;
+            program.instructions[ip].execute(&mut registers);
+            ip = registers[ip_register] + 1;
+        }
+        (ip, registers)
+    }
+
+    #[test]
+    fn matches_writing_the_pointer_through_its_register() {
+        let program = Program::parse(
+            "#ip 2\nseti 7 0 1\ngtrr 2 1 3\neqrr 1 2 4\nmulr 2 2 5\naddr 3 2 2\naddi 2 1 2\n\
+             setr 2 0 0\nbanr 2 1 0\ngtir 9 2 3\naddi 1 1 1\naddr 2 3 2\nseti 0 0 2\nborr 0 2 0",
+            6,
+        )
+        .unwrap();
+        let compiled = program.compile();
+        for limit in 0..60 {
+            let mut machine = Machine::for_program(&compiled);
+            for _ in 0..limit {
+                machine.step(&compiled);
+            }
+            let (ip, registers) = reference_run(&program, limit);
+            assert_eq!((machine.ip, machine.registers()), (ip, &registers[..]), "after {} steps", limit);
+        }
+    }
+}
@@ -0,0 +1,398 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+use crate::{Compiled, Machine, Program};
+
+const PROMPT: &str = "(elfcode) ";
+
+const HELP: &str = "\
+break ADDR [if rN OP VALUE]  stop before the instruction at ADDR, optionally only when the
+                             register compares true (OP is one of == != < <= > >=)
+delete ADDR                  remove every breakpoint at ADDR
+watch rN                     stop whenever register N changes
+unwatch rN                   stop watching register N
+step [N]                     run N instructions (default 1), showing each one
+continue                     run until a breakpoint, a watch or the program halts
+run N                        run N instructions, stopping early at breakpoints and watches
+set rN VALUE                 change a register
+set ip VALUE                 move the instruction pointer
+registers                    show the instruction pointer and registers
+list [ADDR]                  show the instructions around ADDR (default the instruction pointer)
+help                         show this message
+quit                         stop debugging";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Comparison {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Comparison {
+    fn parse(text: &str) -> Option<Comparison> {
+        match text {
+            "==" => Some(Comparison::Equal),
+            "!=" => Some(Comparison::NotEqual),
+            "<" => Some(Comparison::Less),
+            "<=" => Some(Comparison::LessOrEqual),
+            ">" => Some(Comparison::Greater),
+            ">=" => Some(Comparison::GreaterOrEqual),
+            _ => None,
+        }
+    }
+
+    fn holds(self, left: usize, right: usize) -> bool {
+        match self {
+            Comparison::Equal => left == right,
+            Comparison::NotEqual => left != right,
+            Comparison::Less => left < right,
+            Comparison::LessOrEqual => left <= right,
+            Comparison::Greater => left > right,
+            Comparison::GreaterOrEqual => left >= right,
+        }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let symbol = match self {
+            Comparison::Equal => "==",
+            Comparison::NotEqual => "!=",
+            Comparison::Less => "<",
+            Comparison::LessOrEqual => "<=",
+            Comparison::Greater => ">",
+            Comparison::GreaterOrEqual => ">=",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+/// A register comparison that a breakpoint only stops for.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Condition {
+    register: usize,
+    comparison: Comparison,
+    value: usize,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Breakpoint {
+    address: usize,
+    condition: Option<Condition>,
+}
+
+impl Breakpoint {
+    fn hit(&self, ip: usize, registers: &[usize]) -> bool {
+        ip == self.address
+            && self
+                .condition
+                .is_none_or(|condition| condition.comparison.holds(registers[condition.register], condition.value))
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.address)?;
+        if let Some(condition) = self.condition {
+            write!(f, " if r{} {} {}", condition.register, condition.comparison, condition.value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Why the machine stopped running.
+enum Stop {
+    Breakpoint(Breakpoint),
+    Watch { register: usize, from: usize, to: usize },
+    Count,
+    Halted,
+}
+
+/// An interactive debugger for an ElfCode program, reading commands and writing what happens.
+pub struct Debugger<'a> {
+    program: &'a Program,
+    compiled: Compiled,
+    machine: Machine,
+    breakpoints: Vec<Breakpoint>,
+    watches: BTreeSet<usize>,
+}
+
+impl<'a> Debugger<'a> {
+    pub fn new(program: &'a Program) -> Debugger<'a> {
+        let compiled = program.compile();
+        let machine = Machine::for_program(&compiled);
+        Debugger {
+            program,
+            compiled,
+            machine,
+            breakpoints: Vec::new(),
+            watches: BTreeSet::new(),
+        }
+    }
+
+    pub fn machine(&self) -> &Machine {
+        &self.machine
+    }
+
+    /// Reads commands until `quit` or the end of the input, prompting for each one.
+    pub fn repl<R: BufRead, W: Write>(&mut self, input: R, mut output: W) -> io::Result<()> {
+        write!(output, "{}", PROMPT)?;
+        output.flush()?;
+        for line in input.lines() {
+            if !self.command(&line?, &mut output)? {
+                return Ok(());
+            }
+            write!(output, "{}", PROMPT)?;
+            output.flush()?;
+        }
+        writeln!(output)
+    }
+
+    /// Runs a single command, returning false if it was `quit`.
+    pub fn command<W: Write>(&mut self, line: &str, output: &mut W) -> io::Result<bool> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let result = match words.as_slice() {
+            [] => Ok(()),
+            ["quit"] | ["q"] => return Ok(false),
+            ["help"] | ["h"] => writeln!(output, "{}", HELP).map_err(Error::Io),
+            ["break", rest @ ..] | ["b", rest @ ..] => self.add_breakpoint(rest, output),
+            ["delete", address] | ["d", address] => self.delete_breakpoint(address, output),
+            ["watch", register] | ["w", register] => self.watch(register, output),
+            ["unwatch", register] => self.unwatch(register, output),
+            ["step"] | ["s"] => self.step(1, output),
+            ["step", count] | ["s", count] => parse_number(count).and_then(|count| self.step(count, output)),
+            ["continue"] | ["c"] => self.run(None, output),
+            ["run", count] => parse_number(count).and_then(|count| self.run(Some(count), output)),
+            ["set", "ip", value] => parse_number(value).map(|value| self.machine.ip = value),
+            ["set", register, value] => self.set_register(register, value),
+            ["registers"] | ["r"] => self.show_state(output),
+            ["list"] | ["l"] => self.list(self.machine.ip, output),
+            ["list", address] | ["l", address] => parse_number(address).and_then(|address| self.list(address, output)),
+            _ => Err(Error::Usage(format!("Unknown command {:?}, try help", line.trim()))),
+        };
+        match result {
+            Ok(()) => Ok(true),
+            Err(Error::Io(err)) => Err(err),
+            Err(Error::Usage(message)) => writeln!(output, "{}", message).map(|_| true),
+        }
+    }
+
+    fn add_breakpoint<W: Write>(&mut self, words: &[&str], output: &mut W) -> Result<(), Error> {
+        let (address, condition) = match words {
+            [address] => (parse_number(address)?, None),
+            [address, "if", register, comparison, value] => {
+                let comparison = Comparison::parse(comparison)
+                    .ok_or_else(|| Error::Usage(format!("Unknown comparison {:?}", comparison)))?;
+                let condition = Condition {
+                    register: self.parse_register(register)?,
+                    comparison,
+                    value: parse_number(value)?,
+                };
+                (parse_number(address)?, Some(condition))
+            }
+            _ => return Err(Error::Usage(String::from("Expected break ADDR [if rN OP VALUE]"))),
+        };
+        let breakpoint = Breakpoint { address, condition };
+        self.breakpoints.push(breakpoint);
+        writeln!(output, "Breakpoint at {}", breakpoint)?;
+        Ok(())
+    }
+
+    fn delete_breakpoint<W: Write>(&mut self, address: &str, output: &mut W) -> Result<(), Error> {
+        let address = parse_number(address)?;
+        let count = self.breakpoints.len();
+        self.breakpoints.retain(|breakpoint| breakpoint.address != address);
+        writeln!(output, "Deleted {} breakpoint(s) at {}", count - self.breakpoints.len(), address)?;
+        Ok(())
+    }
+
+    fn watch<W: Write>(&mut self, register: &str, output: &mut W) -> Result<(), Error> {
+        let register = self.parse_register(register)?;
+        self.watches.insert(register);
+        writeln!(output, "Watching r{}", register)?;
+        Ok(())
+    }
+
+    fn unwatch<W: Write>(&mut self, register: &str, output: &mut W) -> Result<(), Error> {
+        let register = self.parse_register(register)?;
+        self.watches.remove(&register);
+        writeln!(output, "Stopped watching r{}", register)?;
+        Ok(())
+    }
+
+    fn set_register(&mut self, register: &str, value: &str) -> Result<(), Error> {
+        let register = self.parse_register(register)?;
+        self.machine.registers_mut()[register] = parse_number(value)?;
+        Ok(())
+    }
+
+    fn step<W: Write>(&mut self, count: usize, output: &mut W) -> Result<(), Error> {
+        for _ in 0..count {
+            if self.machine.halted(&self.compiled) {
+                break;
+            }
+            self.show_state(output)?;
+            self.machine.step(&self.compiled);
+        }
+        self.report(Stop::Count, output)
+    }
+
+    /// Runs at most `limit` instructions, or without limit, stopping at breakpoints and watches.
+    /// A breakpoint at the current instruction doesn't stop it, so that it can be continued from.
+    fn run<W: Write>(&mut self, limit: Option<usize>, output: &mut W) -> Result<(), Error> {
+        let breakpoints = &self.breakpoints;
+        let watches = &self.watches;
+        let mut watched: Vec<(usize, usize)> = watches
+            .iter()
+            .map(|&register| (register, self.machine.registers()[register]))
+            .collect();
+        let mut executed = 0;
+        let mut stop = None;
+        self.machine.run_until(&self.compiled, |ip, registers| {
+            for (register, value) in watched.iter_mut() {
+                if registers[*register] != *value {
+                    stop = Some(Stop::Watch {
+                        register: *register,
+                        from: *value,
+                        to: registers[*register],
+                    });
+                    *value = registers[*register];
+                    return true;
+                }
+            }
+            if executed > 0 {
+                if let Some(breakpoint) = breakpoints.iter().find(|breakpoint| breakpoint.hit(ip, registers)) {
+                    stop = Some(Stop::Breakpoint(*breakpoint));
+                    return true;
+                }
+            }
+            if limit == Some(executed) {
+                stop = Some(Stop::Count);
+                return true;
+            }
+            executed += 1;
+            false
+        });
+        self.report(stop.unwrap_or(Stop::Halted), output)
+    }
+
+    fn report<W: Write>(&self, stop: Stop, output: &mut W) -> Result<(), Error> {
+        match stop {
+            Stop::Breakpoint(breakpoint) => writeln!(output, "Stopped at breakpoint {}", breakpoint)?,
+            Stop::Watch { register, from, to } => writeln!(output, "r{} changed from {} to {}", register, from, to)?,
+            Stop::Count => (),
+            Stop::Halted => (),
+        }
+        if self.machine.halted(&self.compiled) {
+            writeln!(
+                output,
+                "Halted after {} instructions with registers {:?}",
+                self.machine.executed,
+                self.machine.registers()
+            )?;
+            return Ok(());
+        }
+        self.show_state(output)
+    }
+
+    fn show_state<W: Write>(&self, output: &mut W) -> Result<(), Error> {
+        let ip = self.machine.ip;
+        match self.program.instructions.get(ip) {
+            Some(instruction) => writeln!(output, "ip={} {:?} {}", ip, self.machine.registers(), instruction)?,
+            None => writeln!(output, "ip={} {:?} (halted)", ip, self.machine.registers())?,
+        }
+        Ok(())
+    }
+
+    fn list<W: Write>(&self, address: usize, output: &mut W) -> Result<(), Error> {
+        let start = address.saturating_sub(5);
+        let end = (address + 6).min(self.program.instructions.len());
+        for (index, instruction) in self.program.instructions.iter().enumerate().take(end).skip(start) {
+            let marker = if index == self.machine.ip { "=>" } else { "  " };
+            let breakpoint = if self.breakpoints.iter().any(|breakpoint| breakpoint.address == index) {
+                "*"
+            } else {
+                " "
+            };
+            writeln!(output, "{}{}{:>4}  {}", marker, breakpoint, index, instruction)?;
+        }
+        Ok(())
+    }
+
+    fn parse_register(&self, text: &str) -> Result<usize, Error> {
+        let register = parse_number(text.trim_start_matches('r'))?;
+        if register >= self.compiled.register_count {
+            return Err(Error::Usage(format!(
+                "There is no register {}, only {} registers",
+                register, self.compiled.register_count
+            )));
+        }
+        Ok(register)
+    }
+}
+
+/// Either a mistake in a command, which is reported and then the next command is read,
+/// or a failure to write the output, which ends the session.
+enum Error {
+    Usage(String),
+    Io(io::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        Error::Io(err)
+    }
+}
+
+fn parse_number(text: &str) -> Result<usize, Error> {
+    text.parse::<usize>()
+        .map_err(|err| Error::Usage(format!("Unable to parse {:?}: {}", text, err)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(program: &str, commands: &str) -> String {
+        let program = Program::parse(program, 6).unwrap();
+        let mut debugger = Debugger::new(&program);
+        let mut output = Vec::new();
+        debugger.repl(commands.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap().replace(PROMPT, "")
+    }
+
+    const COUNTDOWN: &str = "#ip 5\nseti 3 0 1\naddi 1 0 0\naddi 0 10 2\naddi 1 1 1\ngtri 1 5 3\naddr 3 5 5\nseti 0 0 5";
+
+    #[test]
+    fn stops_at_breakpoints_that_hold() {
+        let output = session(COUNTDOWN, "break 3 if r1 == 4\ncontinue\ncontinue\nregisters\nquit\n");
+        assert_eq!(
+            output,
+            "Breakpoint at 3 if r1 == 4\n\
+             Stopped at breakpoint 3 if r1 == 4\n\
+             ip=3 [4, 4, 14, 0, 0, 2] addi 1 1 1\n\
+             Halted after 18 instructions with registers [5, 6, 15, 1, 0, 6]\n\
+             ip=7 [5, 6, 15, 1, 0, 6] (halted)\n"
+        );
+    }
+
+    #[test]
+    fn steps_watches_and_edits_registers() {
+        let output = session(COUNTDOWN, "step 2\nset r1 20\nwatch r2\nrun 100\nrun 1\nfoo\n");
+        assert_eq!(
+            output,
+            "ip=0 [0, 0, 0, 0, 0, 0] seti 3 0 1\n\
+             ip=1 [0, 3, 0, 0, 0, 0] addi 1 0 0\n\
+             ip=2 [3, 3, 0, 0, 0, 1] addi 0 10 2\n\
+             Watching r2\n\
+             r2 changed from 0 to 13\n\
+             ip=3 [3, 20, 13, 0, 0, 2] addi 1 1 1\n\
+             ip=4 [3, 21, 13, 0, 0, 3] gtri 1 5 3\n\
+             Unknown command \"foo\", try help\n\n"
+        );
+    }
+}
@@ -0,0 +1,595 @@
+use std::collections::BTreeSet;
+use std::fmt;
+
+use crate::compiled::inline_ip_register;
+use crate::{Instruction, Op, Program};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum BinaryOp {
+    Add,
+    Mul,
+    And,
+    Or,
+    Greater,
+    LessOrEqual,
+    Equal,
+    NotEqual,
+}
+
+impl BinaryOp {
+    fn symbol(self) -> &'static str {
+        match self {
+            BinaryOp::Add => "+",
+            BinaryOp::Mul => "*",
+            BinaryOp::And => "&",
+            BinaryOp::Or => "|",
+            BinaryOp::Greater => ">",
+            BinaryOp::LessOrEqual => "<=",
+            BinaryOp::Equal => "==",
+            BinaryOp::NotEqual => "!=",
+        }
+    }
+
+    fn is_comparison(self) -> bool {
+        matches!(
+            self,
+            BinaryOp::Greater | BinaryOp::LessOrEqual | BinaryOp::Equal | BinaryOp::NotEqual
+        )
+    }
+
+    fn apply(self, left: usize, right: usize) -> usize {
+        match self {
+            BinaryOp::Add => left + right,
+            BinaryOp::Mul => left * right,
+            BinaryOp::And => left & right,
+            BinaryOp::Or => left | right,
+            BinaryOp::Greater => (left > right) as usize,
+            BinaryOp::LessOrEqual => (left <= right) as usize,
+            BinaryOp::Equal => (left == right) as usize,
+            BinaryOp::NotEqual => (left != right) as usize,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Expr {
+    Register(usize),
+    Value(usize),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+}
+
+impl Expr {
+    /// Combines two expressions, working the result out if both are values.
+    fn binary(op: BinaryOp, left: Expr, right: Expr) -> Expr {
+        match (left, right) {
+            (Expr::Value(left), Expr::Value(right)) => Expr::Value(op.apply(left, right)),
+            (left, right) => Expr::Binary(op, Box::new(left), Box::new(right)),
+        }
+    }
+
+    /// The value of an instruction's result, with its arguments as registers or values.
+    fn of_instruction(instruction: &Instruction) -> Expr {
+        let Instruction { op, a, b, .. } = *instruction;
+        let a = if op.a_is_register() { Expr::Register(a) } else { Expr::Value(a) };
+        let b = if op.b_is_register() { Expr::Register(b) } else { Expr::Value(b) };
+        match op {
+            Op::Addr | Op::Addi => Expr::binary(BinaryOp::Add, a, b),
+            Op::Mulr | Op::Muli => Expr::binary(BinaryOp::Mul, a, b),
+            Op::Banr | Op::Bani => Expr::binary(BinaryOp::And, a, b),
+            Op::Borr | Op::Bori => Expr::binary(BinaryOp::Or, a, b),
+            Op::Setr | Op::Seti => a,
+            Op::Gtir | Op::Gtri | Op::Gtrr => Expr::binary(BinaryOp::Greater, a, b),
+            Op::Eqir | Op::Eqri | Op::Eqrr => Expr::binary(BinaryOp::Equal, a, b),
+        }
+    }
+
+    fn is_comparison(&self) -> bool {
+        match self {
+            Expr::Binary(op, _, _) => op.is_comparison(),
+            Expr::Not(_) => true,
+            _ => false,
+        }
+    }
+
+    fn negate(self) -> Expr {
+        match self {
+            Expr::Binary(BinaryOp::Greater, left, right) => Expr::Binary(BinaryOp::LessOrEqual, left, right),
+            Expr::Binary(BinaryOp::LessOrEqual, left, right) => Expr::Binary(BinaryOp::Greater, left, right),
+            Expr::Binary(BinaryOp::Equal, left, right) => Expr::Binary(BinaryOp::NotEqual, left, right),
+            Expr::Binary(BinaryOp::NotEqual, left, right) => Expr::Binary(BinaryOp::Equal, left, right),
+            Expr::Not(expr) => *expr,
+            expr => Expr::Not(Box::new(expr)),
+        }
+    }
+
+    /// The registers that the expression reads, as a bit set.
+    fn uses(&self) -> u32 {
+        match self {
+            Expr::Register(register) => 1 << register,
+            Expr::Value(_) => 0,
+            Expr::Binary(_, left, right) => left.uses() | right.uses(),
+            Expr::Not(expr) => expr.uses(),
+        }
+    }
+
+    fn count_uses(&self, register: usize) -> usize {
+        match self {
+            Expr::Register(used) => (*used == register) as usize,
+            Expr::Value(_) => 0,
+            Expr::Binary(_, left, right) => left.count_uses(register) + right.count_uses(register),
+            Expr::Not(expr) => expr.count_uses(register),
+        }
+    }
+
+    fn substitute(self, register: usize, replacement: &Expr) -> Expr {
+        match self {
+            Expr::Register(used) if used == register => replacement.clone(),
+            Expr::Binary(op, left, right) => Expr::binary(
+                op,
+                left.substitute(register, replacement),
+                right.substitute(register, replacement),
+            ),
+            Expr::Not(expr) => Expr::Not(Box::new(expr.substitute(register, replacement))),
+            expr => expr,
+        }
+    }
+
+    fn plus_one(self) -> Expr {
+        match self {
+            Expr::Binary(BinaryOp::Add, left, right) => match *right {
+                Expr::Value(value) => Expr::binary(BinaryOp::Add, *left, Expr::Value(value + 1)),
+                right => Expr::binary(BinaryOp::Add, Expr::binary(BinaryOp::Add, *left, right), Expr::Value(1)),
+            },
+            Expr::Value(value) => Expr::Value(value + 1),
+            expr => Expr::binary(BinaryOp::Add, expr, Expr::Value(1)),
+        }
+    }
+}
+
+/// Registers are named a, b, c and so on.
+fn register_name(register: usize) -> char {
+    (b'a' + register as u8) as char
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expr::Register(register) => write!(f, "{}", register_name(*register)),
+            Expr::Value(value) => write!(f, "{}", value),
+            Expr::Binary(op, left, right) => {
+                for (index, side) in [left, right].iter().enumerate() {
+                    if index == 1 {
+                        write!(f, " {} ", op.symbol())?;
+                    }
+                    match ***side {
+                        Expr::Binary(..) => write!(f, "({})", side)?,
+                        _ => write!(f, "{}", side)?,
+                    }
+                }
+                Ok(())
+            }
+            Expr::Not(expr) => match **expr {
+                Expr::Binary(..) => write!(f, "!({})", expr),
+                _ => write!(f, "!{}", expr),
+            },
+        }
+    }
+}
+
+/// What one instruction does, once jumps through the instruction pointer are worked out.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Statement {
+    /// An instruction that has been merged into a later one.
+    Nop,
+    Assign(usize, Expr),
+    Goto(usize),
+    /// Jumps to the address if the condition holds, or else carries on to the next instruction.
+    Branch(Expr, usize),
+    /// Jumps to an address that depends on the registers.
+    Computed(Expr),
+}
+
+impl Statement {
+    fn uses(&self) -> u32 {
+        match self {
+            Statement::Assign(_, expr) | Statement::Branch(expr, _) | Statement::Computed(expr) => expr.uses(),
+            Statement::Nop | Statement::Goto(_) => 0,
+        }
+    }
+
+    fn defines(&self) -> u32 {
+        match self {
+            Statement::Assign(register, _) => 1 << register,
+            _ => 0,
+        }
+    }
+}
+
+/// Turns each instruction into a statement. A comparison followed by adding its result to the
+/// instruction pointer is the usual way to skip the next instruction, so it becomes a branch.
+fn decode_statements(program: &Program) -> Vec<Statement> {
+    let instructions = &program.instructions;
+    let ip_register = match program.ip_register {
+        Some(ip_register) => ip_register,
+        None => {
+            return instructions
+                .iter()
+                .map(|instruction| Statement::Assign(instruction.c, Expr::of_instruction(instruction)))
+                .collect();
+        }
+    };
+    let raw_targets: Vec<Option<usize>> = (0..instructions.len())
+        .map(|address| match inline_ip_register(instructions[address], address, ip_register) {
+            Instruction { op: Op::Seti, a, c, .. } if c == ip_register => Some(a + 1),
+            _ => None,
+        })
+        .collect();
+
+    (0..instructions.len())
+        .map(|address| {
+            let instruction = inline_ip_register(instructions[address], address, ip_register);
+            if instruction.c != ip_register {
+                return Statement::Assign(instruction.c, Expr::of_instruction(&instruction));
+            }
+            if instruction.op == Op::Seti {
+                return Statement::Goto(instruction.a + 1);
+            }
+            let condition = match instruction {
+                Instruction { op: Op::Addi, a, b, .. } if b == address && address > 0 => {
+                    let previous = &instructions[address - 1];
+                    let is_boolean = previous.c == a && is_comparison(previous.op) && previous.c != ip_register;
+                    let is_target = raw_targets.contains(&Some(address));
+                    if is_boolean && !is_target {
+                        Some(a)
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            };
+            match condition {
+                Some(register) => Statement::Branch(Expr::Register(register), address + 2),
+                None => Statement::Computed(Expr::of_instruction(&instruction).plus_one()),
+            }
+        })
+        .collect()
+}
+
+fn is_comparison(op: Op) -> bool {
+    matches!(op, Op::Gtir | Op::Gtri | Op::Gtrr | Op::Eqir | Op::Eqri | Op::Eqrr)
+}
+
+fn jump_targets(statements: &[Statement]) -> BTreeSet<usize> {
+    statements
+        .iter()
+        .filter_map(|statement| match statement {
+            Statement::Goto(target) | Statement::Branch(_, target) => Some(*target),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Merges an assignment into the next statement when that immediately overwrites the register
+/// using its value once, like `e = f * d` followed by `e = e == b`.
+fn merge_assignments(statements: &mut [Statement], targets: &BTreeSet<usize>) {
+    for address in 1..statements.len() {
+        if targets.contains(&address) {
+            continue;
+        }
+        let merged = match (&statements[address - 1], &statements[address]) {
+            (Statement::Assign(first, value), Statement::Assign(second, expr))
+                if first == second && expr.count_uses(*first) == 1 =>
+            {
+                Some(Statement::Assign(*second, expr.clone().substitute(*first, value)))
+            }
+            _ => None,
+        };
+        if let Some(merged) = merged {
+            statements[address] = merged;
+            statements[address - 1] = Statement::Nop;
+        }
+    }
+}
+
+/// The registers that may be read after each statement before being written, as bit sets.
+/// Anything may be read after a computed jump, or once the program halts.
+fn live_after(statements: &[Statement]) -> Vec<u32> {
+    let all = u32::MAX;
+    let mut live_in = vec![0u32; statements.len()];
+    let mut live_out = vec![0u32; statements.len()];
+    let live_at = |live_in: &[u32], address: usize| live_in.get(address).cloned().unwrap_or(all);
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for address in (0..statements.len()).rev() {
+            let out = match &statements[address] {
+                Statement::Nop | Statement::Assign(..) => live_at(&live_in, address + 1),
+                Statement::Goto(target) => live_at(&live_in, *target),
+                Statement::Branch(_, target) => live_at(&live_in, *target) | live_at(&live_in, address + 1),
+                Statement::Computed(_) => all,
+            };
+            let statement = &statements[address];
+            let input = statement.uses() | (out & !statement.defines());
+            if out != live_out[address] || input != live_in[address] {
+                live_out[address] = out;
+                live_in[address] = input;
+                changed = true;
+            }
+        }
+    }
+    live_out
+}
+
+/// Uses the comparison itself as a branch's condition when nothing else reads its result.
+fn fold_conditions(statements: &mut [Statement], targets: &BTreeSet<usize>) {
+    let live = live_after(statements);
+    for address in 1..statements.len() {
+        if targets.contains(&address) {
+            continue;
+        }
+        let folded = match (&statements[address - 1], &statements[address]) {
+            (Statement::Assign(register, comparison), Statement::Branch(Expr::Register(condition), target))
+                if register == condition && comparison.is_comparison() && live[address] & (1 << register) == 0 =>
+            {
+                Some(Statement::Branch(comparison.clone(), *target))
+            }
+            _ => None,
+        };
+        if let Some(folded) = folded {
+            statements[address] = folded;
+            statements[address - 1] = Statement::Nop;
+        }
+    }
+}
+
+/// The loop that the statements being structured are inside, if any.
+#[derive(Clone, Copy)]
+struct Loop {
+    header: usize,
+    exit: usize,
+}
+
+struct Line {
+    address: usize,
+    depth: usize,
+    /// Lines without text just mark where an address starts, in case it needs a label.
+    text: Option<String>,
+}
+
+struct Structurer<'a> {
+    statements: &'a [Statement],
+    lines: Vec<Line>,
+    labels: BTreeSet<usize>,
+}
+
+impl<'a> Structurer<'a> {
+    fn emit(&mut self, address: usize, depth: usize, text: String) {
+        self.lines.push(Line {
+            address,
+            depth,
+            text: Some(text),
+        });
+    }
+
+    /// The furthest statement in `address..end` that jumps back to `address`, making it a loop.
+    fn latch(&self, address: usize, end: usize) -> Option<usize> {
+        (address..end).rev().find(|&latch| match &self.statements[latch] {
+            Statement::Goto(target) | Statement::Branch(_, target) => *target == address,
+            _ => false,
+        })
+    }
+
+    /// How to say "go to `target`" from inside `current`.
+    fn jump(&mut self, target: usize, current: Option<Loop>) -> String {
+        if target >= self.statements.len() {
+            return String::from("halt");
+        }
+        match current {
+            Some(current) if current.header == target => String::from("continue"),
+            Some(current) if current.exit == target => String::from("break"),
+            _ => {
+                self.labels.insert(target);
+                format!("goto L{}", target)
+            }
+        }
+    }
+
+    fn is_structured_jump(&self, target: usize, current: Option<Loop>) -> bool {
+        target >= self.statements.len() || current.is_some_and(|current| target == current.header || target == current.exit)
+    }
+
+    /// Structures the statements in `start..end`, which are inside `current`. The loop at
+    /// `start` is skipped when it's the one being structured.
+    fn region(&mut self, start: usize, end: usize, current: Option<Loop>, depth: usize) {
+        let mut address = start;
+        while address < end {
+            self.lines.push(Line {
+                address,
+                depth,
+                text: None,
+            });
+            let is_current_header = current.is_some_and(|current| current.header == address);
+            if !is_current_header {
+                if let Some(latch) = self.latch(address, end) {
+                    self.emit(address, depth, String::from("loop {"));
+                    let inner = Loop {
+                        header: address,
+                        exit: latch + 1,
+                    };
+                    self.region(address, latch + 1, Some(inner), depth + 1);
+                    self.emit(address, depth, String::from("}"));
+                    address = latch + 1;
+                    continue;
+                }
+            }
+            let is_latch = current.is_some_and(|current| current.exit == address + 1);
+            match &self.statements[address] {
+                Statement::Nop => (),
+                Statement::Assign(register, expr) => {
+                    let text = assignment(*register, expr);
+                    self.emit(address, depth, text);
+                }
+                Statement::Goto(target) => {
+                    let at_latch = is_latch && current.is_some_and(|current| current.header == *target);
+                    if *target != address + 1 && !at_latch {
+                        let text = self.jump(*target, current);
+                        self.emit(address, depth, text);
+                    }
+                }
+                Statement::Branch(condition, target) => {
+                    let (condition, target) = (condition.clone(), *target);
+                    if is_latch && current.is_some_and(|current| current.header == target) {
+                        // Going round again is the default at the end of a loop, so stop otherwise
+                        self.emit(address, depth, format!("if {} {{", condition.negate()));
+                        self.emit(address, depth + 1, String::from("break"));
+                        self.emit(address, depth, String::from("}"));
+                    } else if target > address && target <= end && !self.is_structured_jump(target, current) {
+                        address = self.if_statement(address, condition, target, end, current, depth);
+                        continue;
+                    } else {
+                        let jump = self.jump(target, current);
+                        self.emit(address, depth, format!("if {} {{", condition));
+                        self.emit(address, depth + 1, jump);
+                        self.emit(address, depth, String::from("}"));
+                    }
+                }
+                Statement::Computed(expr) => self.emit(address, depth, format!("goto *({})", expr)),
+            }
+            address += 1;
+        }
+    }
+
+    /// Structures a branch forward to `target` as an if statement around the statements it
+    /// skips, with an else when they end by jumping over the statements after `target`.
+    /// Returns the address to carry on from.
+    fn if_statement(
+        &mut self,
+        address: usize,
+        condition: Expr,
+        target: usize,
+        end: usize,
+        current: Option<Loop>,
+        depth: usize,
+    ) -> usize {
+        let else_end = match &self.statements[target - 1] {
+            Statement::Goto(after) if target - 1 > address && *after > target && *after <= end => Some(*after),
+            _ => None,
+        };
+        match else_end {
+            Some(after) if target - 1 == address + 1 => {
+                // Nothing happens when the condition doesn't hold, so only the else is left
+                self.emit(address, depth, format!("if {} {{", condition));
+                self.region(target, after, current, depth + 1);
+                self.emit(address, depth, String::from("}"));
+                after
+            }
+            Some(after) => {
+                self.emit(address, depth, format!("if {} {{", condition.negate()));
+                self.region(address + 1, target - 1, current, depth + 1);
+                self.emit(address, depth, String::from("} else {"));
+                self.region(target, after, current, depth + 1);
+                self.emit(address, depth, String::from("}"));
+                after
+            }
+            None => {
+                self.emit(address, depth, format!("if {} {{", condition.negate()));
+                self.region(address + 1, target, current, depth + 1);
+                self.emit(address, depth, String::from("}"));
+                target
+            }
+        }
+    }
+
+    fn render(&self) -> String {
+        let mut output = String::new();
+        let mut labelled = BTreeSet::new();
+        for line in &self.lines {
+            if self.labels.contains(&line.address) && labelled.insert(line.address) {
+                output.push_str(&format!("{}L{}:\n", "    ".repeat(line.depth.saturating_sub(1)), line.address));
+            }
+            if let Some(text) = &line.text {
+                output.push_str(&format!("{}{}\n", "    ".repeat(line.depth), text));
+            }
+        }
+        output
+    }
+}
+
+fn assignment(register: usize, expr: &Expr) -> String {
+    if let Expr::Binary(op, left, right) = expr {
+        // The arithmetic ops are all commutative, so the register can be on either side
+        if !op.is_comparison() && **left == Expr::Register(register) {
+            return format!("{} {}= {}", register_name(register), op.symbol(), right);
+        }
+        if !op.is_comparison() && **right == Expr::Register(register) {
+            return format!("{} {}= {}", register_name(register), op.symbol(), left);
+        }
+    }
+    format!("{} = {}", register_name(register), expr)
+}
+
+/// Decompiles a program into pseudocode, with loops and if statements recovered from the
+/// jumps through the instruction pointer. Registers are named a, b, c and so on, and the
+/// instruction pointer's register disappears into the control flow. Anything that can't be
+/// structured is left as a `goto`, and jumps that depend on the registers as `goto *(...)`.
+pub fn decompile(program: &Program) -> String {
+    let mut statements = decode_statements(program);
+    let targets = jump_targets(&statements);
+    merge_assignments(&mut statements, &targets);
+    fold_conditions(&mut statements, &targets);
+
+    let mut structurer = Structurer {
+        statements: &statements,
+        lines: Vec::new(),
+        labels: BTreeSet::new(),
+    };
+    structurer.region(0, statements.len(), None, 0);
+    structurer.render()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recovers_loops_and_ifs() {
+        // Adds up the numbers from 1 to 10 that are multiples of 3. Every register can be read
+        // once the program halts, so the last comparison has to stay
+        let program = Program::parse(
+            "#ip 5\nseti 0 0 0\nseti 1 0 1\nseti 0 0 2\naddi 2 3 2\ngtrr 2 1 3\naddr 3 5 5\n\
+             seti 2 0 5\neqrr 2 1 3\naddr 3 5 5\naddi 5 1 5\naddr 0 1 0\naddi 1 1 1\ngtri 1 10 3\n\
+             addr 3 5 5\nseti 1 0 5",
+            6,
+        )
+        .unwrap();
+        assert_eq!(
+            decompile(&program),
+            "a = 0\n\
+             b = 1\n\
+             loop {\n\
+             \x20   c = 0\n\
+             \x20   loop {\n\
+             \x20       c += 3\n\
+             \x20       if c > b {\n\
+             \x20           break\n\
+             \x20       }\n\
+             \x20   }\n\
+             \x20   if c == b {\n\
+             \x20       a += b\n\
+             \x20   }\n\
+             \x20   b += 1\n\
+             \x20   d = b > 10\n\
+             \x20   if d {\n\
+             \x20       halt\n\
+             \x20   }\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn leaves_jumps_it_cannot_follow_as_gotos() {
+        let program = Program::parse("#ip 1\nseti 3 0 0\naddr 1 0 1\nseti 9 0 1\nmuli 0 2 0", 2).unwrap();
+        assert_eq!(decompile(&program), "a = 3\ngoto *(a + 2)\nhalt\na *= 2\n");
+    }
+}
@@ -0,0 +1,267 @@
+//! The ElfCode instruction set from days 16, 19 and 21: sixteen register machine ops,
+//! programs that may bind the instruction pointer to a register, and a machine to run them.
+#[macro_use]
+extern crate lazy_static;
+extern crate regex;
+
+use std::fmt;
+
+use aoc_common::{InputError, Line, Lines};
+use regex::Regex;
+
+mod compiled;
+mod debugger;
+mod decompiler;
+mod machine;
+mod profiler;
+pub use compiled::{Compiled, MAX_REGISTERS};
+pub use debugger::Debugger;
+pub use decompiler::decompile;
+pub use machine::Machine;
+pub use profiler::{Loop, Profile};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum Op {
+    Addr,
+    Addi,
+    Mulr,
+    Muli,
+    Banr,
+    Bani,
+    Borr,
+    Bori,
+    Setr,
+    Seti,
+    Gtir,
+    Gtri,
+    Gtrr,
+    Eqir,
+    Eqri,
+    Eqrr,
+}
+
+impl Op {
+    pub const ALL: [Op; 16] = [
+        Op::Addr,
+        Op::Addi,
+        Op::Mulr,
+        Op::Muli,
+        Op::Banr,
+        Op::Bani,
+        Op::Borr,
+        Op::Bori,
+        Op::Setr,
+        Op::Seti,
+        Op::Gtir,
+        Op::Gtri,
+        Op::Gtrr,
+        Op::Eqir,
+        Op::Eqri,
+        Op::Eqrr,
+    ];
+
+    /// The op's mnemonic, as written in programs.
+    pub fn name(self) -> &'static str {
+        match self {
+            Op::Addr => "addr",
+            Op::Addi => "addi",
+            Op::Mulr => "mulr",
+            Op::Muli => "muli",
+            Op::Banr => "banr",
+            Op::Bani => "bani",
+            Op::Borr => "borr",
+            Op::Bori => "bori",
+            Op::Setr => "setr",
+            Op::Seti => "seti",
+            Op::Gtir => "gtir",
+            Op::Gtri => "gtri",
+            Op::Gtrr => "gtrr",
+            Op::Eqir => "eqir",
+            Op::Eqri => "eqri",
+            Op::Eqrr => "eqrr",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Op> {
+        Op::ALL.iter().cloned().find(|op| op.name() == name)
+    }
+
+    /// Whether the op reads its first argument from a register, rather than using it as a value.
+    pub fn a_is_register(self) -> bool {
+        !matches!(self, Op::Seti | Op::Gtir | Op::Eqir)
+    }
+
+    /// Whether the op reads its second argument from a register. Some ops ignore it entirely.
+    pub fn b_is_register(self) -> bool {
+        matches!(
+            self,
+            Op::Addr | Op::Mulr | Op::Banr | Op::Borr | Op::Gtir | Op::Gtrr | Op::Eqir | Op::Eqrr
+        )
+    }
+
+    /// Whether the op uses its second argument at all.
+    pub fn uses_b(self) -> bool {
+        !matches!(self, Op::Setr | Op::Seti)
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// An op and its arguments: `a` and `b` are inputs, and `c` is the register that's written.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Instruction {
+    pub op: Op,
+    pub a: usize,
+    pub b: usize,
+    pub c: usize,
+}
+
+impl Instruction {
+    pub fn new(op: Op, a: usize, b: usize, c: usize) -> Instruction {
+        Instruction { op, a, b, c }
+    }
+
+    /// Applies the instruction to the registers in place.
+    /// Panics if the instruction refers to a register that doesn't exist.
+    pub fn execute(&self, registers: &mut [usize]) {
+        let Instruction { op, a, b, c } = *self;
+        registers[c] = match op {
+            Op::Addr => registers[a] + registers[b],
+            Op::Addi => registers[a] + b,
+            Op::Mulr => registers[a] * registers[b],
+            Op::Muli => registers[a] * b,
+            Op::Banr => registers[a] & registers[b],
+            Op::Bani => registers[a] & b,
+            Op::Borr => registers[a] | registers[b],
+            Op::Bori => registers[a] | b,
+            Op::Setr => registers[a],
+            Op::Seti => a,
+            Op::Gtir => (a > registers[b]) as usize,
+            Op::Gtri => (registers[a] > b) as usize,
+            Op::Gtrr => (registers[a] > registers[b]) as usize,
+            Op::Eqir => (a == registers[b]) as usize,
+            Op::Eqri => (registers[a] == b) as usize,
+            Op::Eqrr => (registers[a] == registers[b]) as usize,
+        };
+    }
+
+    /// Whether every register that the instruction reads or writes is one of the first `count`.
+    pub fn fits_registers(&self, count: usize) -> bool {
+        (!self.op.a_is_register() || self.a < count) && (!self.op.b_is_register() || self.b < count) && self.c < count
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {} {}", self.op, self.a, self.b, self.c)
+    }
+}
+
+/// A list of instructions, along with the register that the instruction pointer is bound to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Program {
+    pub ip_register: Option<usize>,
+    pub register_count: usize,
+    pub instructions: Vec<Instruction>,
+}
+
+impl Program {
+    /// Assembles a program from its text: an optional `#ip N` declaration, then one
+    /// instruction per line. Every register must be one of the machine's `register_count`.
+    pub fn parse(input: &str, register_count: usize) -> Result<Program, InputError> {
+        read_program_from_lines(aoc_common::lines(input), register_count)
+    }
+}
+
+/// Formats the program as text that `Program::parse` reads back.
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(ip_register) = self.ip_register {
+            writeln!(f, "#ip {}", ip_register)?;
+        }
+        for instruction in &self.instructions {
+            writeln!(f, "{}", instruction)?;
+        }
+        Ok(())
+    }
+}
+
+fn read_program_from_lines(lines: Lines, register_count: usize) -> Result<Program, InputError> {
+    lazy_static! {
+        static ref header_regex: Regex = Regex::new(r"^#ip (\d+)$").unwrap();
+    }
+
+    let mut lines = lines.filter(|line| !line.text.trim().is_empty()).peekable();
+    let mut ip_register = None;
+    if let Some(header) = lines.peek().cloned().filter(|line| line.text.starts_with('#')) {
+        lines.next();
+        let captures = header.captures(&header_regex, "an instruction pointer declaration like \"#ip 0\"")?;
+        let register = header.parse::<usize>(&captures[1])?;
+        if register >= register_count {
+            return Err(header.error_at(
+                &captures[1],
+                format!("The instruction pointer must be bound to one of the {} registers", register_count),
+            ));
+        }
+        ip_register = Some(register);
+    }
+    let instructions = lines
+        .map(|line| read_instruction_from_line(line, register_count))
+        .collect::<Result<_, _>>()?;
+    Ok(Program {
+        ip_register,
+        register_count,
+        instructions,
+    })
+}
+
+fn read_instruction_from_line(line: Line, register_count: usize) -> Result<Instruction, InputError> {
+    let sections = line.fields(" ", 4)?;
+    let op = Op::from_name(sections[0])
+        .ok_or_else(|| line.error_at(sections[0], format!("Unknown opcode {:?}", sections[0])))?;
+    let instruction = Instruction::new(
+        op,
+        line.parse::<usize>(sections[1])?,
+        line.parse::<usize>(sections[2])?,
+        line.parse::<usize>(sections[3])?,
+    );
+    let registers = [
+        (op.a_is_register(), instruction.a, sections[1]),
+        (op.b_is_register(), instruction.b, sections[2]),
+        (true, instruction.c, sections[3]),
+    ];
+    for &(is_register, register, field) in &registers {
+        if is_register && register >= register_count {
+            return Err(line.error_at(
+                field,
+                format!("There is no register {}, only {} registers", register, register_count),
+            ));
+        }
+    }
+    Ok(instruction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_what_it_parses() {
+        let text = "#ip 0\nseti 5 0 1\naddi 0 1 0\neqrr 1 2 3\n";
+        let program = Program::parse(text, 6).unwrap();
+        assert_eq!(program.ip_register, Some(0));
+        assert_eq!(program.instructions[2], Instruction::new(Op::Eqrr, 1, 2, 3));
+        assert_eq!(program.to_string(), text);
+    }
+
+    #[test]
+    fn rejects_registers_that_do_not_exist() {
+        let error = Program::parse("seti 9 0 1\naddr 4 0 1", 4).unwrap_err();
+        assert_eq!(error.line_number(), 2);
+        assert_eq!(error.message(), "There is no register 4, only 4 registers");
+    }
+}
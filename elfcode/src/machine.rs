@@ -0,0 +1,126 @@
+use crate::compiled::{self, Compiled, MAX_REGISTERS};
+
+/// A register machine running ElfCode. If the program binds the instruction pointer to a
+/// register, the register holds the pointer while each instruction runs, so instructions can
+/// jump by writing to it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Machine {
+    registers: [usize; MAX_REGISTERS],
+    register_count: usize,
+    pub ip: usize,
+    /// How many instructions the machine has run.
+    pub executed: u64,
+}
+
+impl Machine {
+    pub fn new(register_count: usize) -> Machine {
+        assert!(
+            register_count <= MAX_REGISTERS,
+            "A machine can have at most {} registers",
+            MAX_REGISTERS
+        );
+        Machine {
+            registers: [0; MAX_REGISTERS],
+            register_count,
+            ip: 0,
+            executed: 0,
+        }
+    }
+
+    /// A machine with as many registers as the program uses.
+    pub fn for_program(program: &Compiled) -> Machine {
+        Machine::new(program.register_count)
+    }
+
+    pub fn registers(&self) -> &[usize] {
+        &self.registers[..self.register_count]
+    }
+
+    pub fn registers_mut(&mut self) -> &mut [usize] {
+        &mut self.registers[..self.register_count]
+    }
+
+    /// Whether the instruction pointer has left the program.
+    pub fn halted(&self, program: &Compiled) -> bool {
+        self.ip >= program.len()
+    }
+
+    /// Runs the instruction at the instruction pointer, returning false if the machine had
+    /// already halted.
+    pub fn step(&mut self, program: &Compiled) -> bool {
+        if self.halted(program) {
+            return false;
+        }
+        self.ip = compiled::execute_at(program, self.ip, &mut self.registers);
+        self.executed += 1;
+        true
+    }
+
+    /// Runs until the instruction pointer leaves the program.
+    pub fn run(&mut self, program: &Compiled) {
+        self.run_until(program, |_, _| false);
+    }
+
+    /// Runs until `stop`, given the instruction pointer and registers before each instruction,
+    /// returns true, returning true, or until the program halts, returning false.
+    #[inline]
+    pub fn run_until<F: FnMut(usize, &[usize]) -> bool>(&mut self, program: &Compiled, mut stop: F) -> bool {
+        // Work on copies, so that they can stay in CPU registers rather than memory
+        let mut registers = self.registers;
+        let mut ip = self.ip;
+        let mut executed = self.executed;
+        let mut stopped = false;
+        while ip < program.len() {
+            if stop(ip, &registers[..self.register_count]) {
+                stopped = true;
+                break;
+            }
+            ip = compiled::execute_at(program, ip, &mut registers);
+            executed += 1;
+        }
+        self.registers = registers;
+        self.ip = ip;
+        self.executed = executed;
+        stopped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Instruction, Op, Program};
+
+    #[test]
+    fn jumps_through_the_bound_register() {
+        let program = Program::parse(
+            "#ip 0\nseti 5 0 1\nseti 6 0 2\naddi 0 1 0\naddr 1 2 3\nsetr 1 0 0\nseti 8 0 4\nseti 9 0 5",
+            6,
+        )
+        .unwrap()
+        .compile();
+        let mut machine = Machine::for_program(&program);
+        machine.run(&program);
+        assert_eq!(machine.registers(), &[6, 5, 6, 0, 0, 9]);
+        assert_eq!(machine.executed, 5);
+    }
+
+    #[test]
+    fn runs_straight_through_without_a_bound_register() {
+        let instructions = [Instruction::new(Op::Seti, 3, 0, 1), Instruction::new(Op::Mulr, 1, 1, 0)];
+        let program = Compiled::new(&instructions, 4, None);
+        let mut machine = Machine::new(4);
+        machine.run(&program);
+        assert_eq!(machine.registers(), &[9, 3, 0, 0]);
+        assert!(machine.halted(&program));
+    }
+
+    #[test]
+    fn stops_before_the_chosen_instruction() {
+        let program = Program::parse("seti 1 0 0\nseti 2 0 0\nseti 3 0 0", 1).unwrap().compile();
+        let mut machine = Machine::for_program(&program);
+        assert!(machine.run_until(&program, |ip, _| ip == 2));
+        assert_eq!(machine.registers(), &[2]);
+        assert!(!machine.run_until(&program, |ip, registers| ip == 2 && registers[0] != 2));
+        assert_eq!(machine.registers(), &[3]);
+    }
+}
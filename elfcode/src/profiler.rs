@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::{Instruction, Machine, Program};
+
+/// A range of addresses that the program jumped backwards over, from `start` to `end`
+/// inclusive.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Loop {
+    pub start: usize,
+    pub end: usize,
+    /// How many times the program jumped from the end back to the start.
+    pub iterations: u64,
+    /// How many instructions ran within the range, including any loops nested in it.
+    pub executed: u64,
+}
+
+/// Where a program spent its time while running.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Profile {
+    pub instructions: Vec<Instruction>,
+    /// How many times each instruction ran, by address.
+    pub counts: Vec<u64>,
+    pub executed: u64,
+    /// Whether the program halted, rather than running out of instructions to run.
+    pub halted: bool,
+    /// The loops that the program went round, hottest first.
+    pub loops: Vec<Loop>,
+    /// The smallest and largest value each register held.
+    pub register_ranges: Vec<(usize, usize)>,
+}
+
+impl Profile {
+    /// Runs the program on the machine until it halts, or until it has run `limit`
+    /// instructions, counting what it executes along the way.
+    pub fn run(program: &Program, machine: &mut Machine, limit: Option<u64>) -> Profile {
+        let compiled = program.compile();
+        let mut counts = vec![0; program.instructions.len()];
+        let mut back_edges: BTreeMap<(usize, usize), u64> = BTreeMap::new();
+        let mut register_ranges: Vec<(usize, usize)> = machine.registers().iter().map(|&value| (value, value)).collect();
+        let mut previous = None;
+        let mut executed = 0;
+        let stopped = machine.run_until(&compiled, |ip, registers| {
+            if limit == Some(executed) {
+                return true;
+            }
+            update_ranges(&mut register_ranges, registers);
+            if let Some(previous) = previous.filter(|&previous| ip <= previous) {
+                *back_edges.entry((ip, previous)).or_insert(0) += 1;
+            }
+            counts[ip] += 1;
+            executed += 1;
+            previous = Some(ip);
+            false
+        });
+        update_ranges(&mut register_ranges, machine.registers());
+
+        let mut loops: Vec<Loop> = back_edges
+            .into_iter()
+            .map(|((start, end), iterations)| Loop {
+                start,
+                end,
+                iterations,
+                executed: counts[start..=end].iter().sum(),
+            })
+            .collect();
+        loops.sort_by_key(|found| (std::cmp::Reverse(found.executed), found.start, found.end));
+        Profile {
+            instructions: program.instructions.clone(),
+            counts,
+            executed,
+            halted: !stopped,
+            loops,
+            register_ranges,
+        }
+    }
+}
+
+fn update_ranges(ranges: &mut [(usize, usize)], registers: &[usize]) {
+    for (range, &value) in ranges.iter_mut().zip(registers) {
+        range.0 = range.0.min(value);
+        range.1 = range.1.max(value);
+    }
+}
+
+fn percentage(count: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 * 100.0 / total as f64
+    }
+}
+
+/// Formats the profile as tables of the instructions, the loops and the registers.
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{} instructions executed, {}",
+            self.executed,
+            if self.halted { "halted" } else { "stopped at the limit" }
+        )?;
+        writeln!(f)?;
+        writeln!(f, "{:>7}  {:>14}  {:>6}  instruction", "address", "count", "%")?;
+        for (address, (instruction, &count)) in self.instructions.iter().zip(&self.counts).enumerate() {
+            writeln!(
+                f,
+                "{:>7}  {:>14}  {:>5.1}%  {}",
+                address,
+                count,
+                percentage(count, self.executed),
+                instruction
+            )?;
+        }
+        if !self.loops.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "{:>13}  {:>14}  {:>14}  {:>6}", "loop", "iterations", "executed", "%")?;
+            for found in &self.loops {
+                writeln!(
+                    f,
+                    "{:>13}  {:>14}  {:>14}  {:>5.1}%",
+                    format!("{}..={}", found.start, found.end),
+                    found.iterations,
+                    found.executed,
+                    percentage(found.executed, self.executed)
+                )?;
+            }
+        }
+        writeln!(f)?;
+        writeln!(f, "{:>8}  {:>14}  {:>14}", "register", "min", "max")?;
+        for (register, (min, max)) in self.register_ranges.iter().enumerate() {
+            writeln!(f, "{:>8}  {:>14}  {:>14}", format!("r{}", register), min, max)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Adds up 1, 2 and 3 into r0, counting with r1
+    const SUM_TO_THREE: &str = "#ip 3\nseti 0 0 2\naddi 1 1 1\naddr 0 1 0\ngtri 1 2 2\naddr 2 3 3\nseti 0 0 3";
+
+    #[test]
+    fn counts_instructions_loops_and_ranges() {
+        let program = Program::parse(SUM_TO_THREE, 4).unwrap();
+        let profile = Profile::run(&program, &mut Machine::new(4), None);
+        assert!(profile.halted);
+        assert_eq!(profile.executed, 15);
+        assert_eq!(profile.counts, vec![1, 3, 3, 3, 3, 2]);
+        assert_eq!(
+            profile.loops,
+            vec![Loop {
+                start: 1,
+                end: 5,
+                iterations: 2,
+                executed: 14,
+            }]
+        );
+        assert_eq!(profile.register_ranges[..3], [(0, 6), (0, 3), (0, 1)]);
+    }
+
+    #[test]
+    fn stops_at_the_limit() {
+        let program = Program::parse(SUM_TO_THREE, 4).unwrap();
+        let mut machine = Machine::new(4);
+        let profile = Profile::run(&program, &mut machine, Some(4));
+        assert!(!profile.halted);
+        assert_eq!(profile.executed, 4);
+        assert_eq!(profile.counts, vec![1, 1, 1, 1, 0, 0]);
+        assert_eq!(machine.ip, 4);
+    }
+}